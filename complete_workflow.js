@@ -80,7 +80,7 @@ async function main() {
     // Milestones: 30%, 30%, 40% (in basis points: 3000, 3000, 4000)
     const milestones = [3000, 3000, 4000];
     
-    await api.escrow.createProject(projectBudget, milestones, projectOwner);
+    const { projectId } = await api.escrow.createProject(projectBudget, milestones, projectOwner);
 
     // ========================================================================
     // Step 3: Set Verifier (Verification Bridge)
@@ -98,7 +98,7 @@ async function main() {
     console.log('STEP 4: Setup Verification Bridge');
     console.log('='.repeat(70));
     
-    await api.bridge.setRelayer(1, relayer.address, projectOwner);
+    await api.bridge.addRelayer(relayer.address, 'ci-runner', projectOwner);
     await api.bridge.setEscrowManager(addresses.escrowManager, projectOwner);
 
     // ========================================================================
@@ -108,16 +108,21 @@ async function main() {
     console.log('STEP 5: Fund Project (Transfer USDC to Escrow)');
     console.log('='.repeat(70));
     
-    // First approve USDC transfer
-    await api.usdc.transfer(addresses.escrowManager, projectBudget, projectOwner);
+    // First approve the escrow to pull the budget
+    await api.usdc.approve(addresses.escrowManager, projectBudget, projectOwner);
     
-    // Then call fund_project
-    await api.escrow.fundProject(projectOwner);
+    // Then call fund_project, which transfers the budget into the escrow
+    await api.escrow.fundProject(projectId, projectOwner);
+    
+    // Pools follow the project's split of the budget net of the 5% platform fee
+    const { upfrontPool, progressPool, finalPool } = await api.escrow.getProject(projectId);
+    const fee = projectBudget - upfrontPool - progressPool - finalPool;
     
     console.log('💰 Breakdown:');
-    console.log(`   60% (${projectBudget * 60n / 100n}) → Progress Pool`);
-    console.log(`   35% (${projectBudget * 35n / 100n}) → Final Pool`);
-    console.log(`   5% (${projectBudget * 5n / 100n}) → Treasury Fee`);
+    console.log(`   ${upfrontPool} → Upfront (credited at developer selection)`);
+    console.log(`   ${progressPool} → Progress Pool`);
+    console.log(`   ${finalPool} → Final Pool`);
+    console.log(`   ${fee} → Treasury Fee`);
 
     // ========================================================================
     // Step 6: Select Developer
@@ -126,7 +131,7 @@ async function main() {
     console.log('STEP 6: Select Developer');
     console.log('='.repeat(70));
     
-    await api.escrow.selectDeveloper(developer.address, projectOwner);
+    await api.escrow.selectDeveloper(projectId, developer.address, projectOwner);
 
     // ========================================================================
     // Step 7: Milestone 1 Completion (30%)
@@ -136,9 +141,9 @@ async function main() {
     console.log('='.repeat(70));
     
     // Relayer submits attestation from Hyperliquid
-    await api.bridge.submitAttestation(1, 10000, relayer); // 100% of milestone 1 = 30% of project
+    await api.bridge.submitAttestation(projectId, 1, 10000, relayer); // 100% of milestone 1 = 30% of project
     
-    console.log(`✅ Milestone 1 complete! Developer received ~${projectBudget * 30n / 100n} USDC`);
+    console.log(`✅ Milestone 1 complete! Developer credited ~${progressPool * 30n / 100n} USDC`);
 
     // ========================================================================
     // Step 8: Milestone 2 Completion (30%)
@@ -147,9 +152,9 @@ async function main() {
     console.log('STEP 8: Milestone 2 - Complete (30%)');
     console.log('='.repeat(70));
     
-    await api.bridge.submitAttestation(2, 10000, relayer); // 100% of milestone 2 = 30% of project
+    await api.bridge.submitAttestation(projectId, 2, 10000, relayer); // 100% of milestone 2 = 30% of project
     
-    console.log(`✅ Milestone 2 complete! Developer received another ~${projectBudget * 30n / 100n} USDC`);
+    console.log(`✅ Milestone 2 complete! Developer credited another ~${progressPool * 30n / 100n} USDC`);
 
     // ========================================================================
     // Step 9: Milestone 3 Partial Progress (20% of 40%)
@@ -158,9 +163,9 @@ async function main() {
     console.log('STEP 9: Milestone 3 - Partial (50% = 20% of project)');
     console.log('='.repeat(70));
     
-    await api.bridge.submitAttestation(3, 5000, relayer); // 50% of milestone 3 = 20% of project
+    await api.bridge.submitAttestation(projectId, 3, 5000, relayer); // 50% of milestone 3 = 20% of project
     
-    console.log(`✅ Milestone 3 partial! Developer received ~${projectBudget * 20n / 100n} USDC (50% of milestone 3)`);
+    console.log(`✅ Milestone 3 partial! Developer credited ~${progressPool * 20n / 100n} USDC (50% of milestone 3)`);
 
     // ========================================================================
     // Step 10: Milestone 3 Complete (remaining 20%)
@@ -169,22 +174,33 @@ async function main() {
     console.log('STEP 10: Milestone 3 - Complete (100% = 40% of project)');
     console.log('='.repeat(70));
     
-    await api.bridge.submitAttestation(3, 10000, relayer); // 100% of milestone 3
+    await api.bridge.submitAttestation(projectId, 3, 10000, relayer); // 100% of milestone 3
     
-    console.log(`✅ Milestone 3 complete! Developer received final ~${projectBudget * 20n / 100n} USDC`);
-    console.log(`📊 Progress Pool fully released: ${projectBudget * 60n / 100n} USDC total`);
+    console.log(`✅ Milestone 3 complete! Developer credited final ~${progressPool * 20n / 100n} USDC`);
+    console.log(`📊 Progress Pool fully released: ${progressPool} USDC total`);
 
     // ========================================================================
-    // Step 11: Final Delivery Approval (35%)
+    // Step 11: Final Delivery Approval
     // ========================================================================
     console.log('\n' + '='.repeat(70));
     console.log('STEP 11: Final Delivery Approval');
     console.log('='.repeat(70));
     
-    await api.escrow.markFinalApproved(projectOwner);
+    await api.escrow.markFinalApproved(projectId, projectOwner);
+    
+    const developerEarnings = upfrontPool + progressPool + finalPool;
+    console.log(`✅ Final payment released: ${finalPool} USDC`);
+    console.log(`📊 Total developer earnings: ${developerEarnings} USDC (of ${projectBudget})`);
+
+    // ========================================================================
+    // Step 12: Developer Withdraws
+    // ========================================================================
+    console.log('\n' + '='.repeat(70));
+    console.log('STEP 12: Developer Withdraws Credited Payouts');
+    console.log('='.repeat(70));
     
-    console.log(`✅ Final payment released: ${projectBudget * 35n / 100n} USDC`);
-    console.log(`📊 Total developer earnings: ${projectBudget * 95n / 100n} USDC (95% of ${projectBudget})`);
+    const owed = await api.escrow.getOwed(developer.address);
+    await api.escrow.withdraw(owed, developer);
 
     // ========================================================================
     // Summary
@@ -194,9 +210,9 @@ async function main() {
     console.log('╚════════════════════════════════════════════════════════════════╝\n');
     
     console.log('✅ All milestones completed successfully!');
-    console.log(`   Developer earned: ${projectBudget * 95n / 100n} USDC`);
-    console.log(`   Treasury fee: ${projectBudget * 5n / 100n} USDC`);
-    console.log('   Payment distribution: 60% progressive + 35% final = 95% to developer');
+    console.log(`   Developer earned: ${developerEarnings} USDC`);
+    console.log(`   Treasury fee: ${fee} USDC`);
+    console.log('   Payment distribution: 60% progressive + 40% final of the budget net of the fee');
 
   } catch (error) {
     console.error('\n❌ Error:', error.message);
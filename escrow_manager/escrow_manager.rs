@@ -1,188 +1,914 @@
 #
 ![no_std]
-use gstd::{msg, prelude::*, ActorId};
+use core::ptr::addr_of_mut;
+
+use gstd::{collections::BTreeMap, exec, msg, prelude::*, ActorId};
+use hvs_io::{
+    decode_action, Dispute, Error, EscrowAction, EscrowEvent, EscrowInit, EscrowReply, Milestone, MilestoneSpec,
+    Payee, PaymentSplit, PolicyReason, Project, ProjectId, ProjectStatus, ShareProposal, Stream, TokenAction,
+    TokenReply,
+};
 
 // State variables using static mut, as required.
-static mut OWNER: [u8; 32] = [0; 32];
-static mut DEVELOPER: [u8; 32] = [0; 32];
-static mut BUDGET: u128 = 0;
-static mut IS_ACTIVE: bool = false;
-static mut IS_FUNDED: bool = false;
-static mut DEVELOPER_SELECTED: bool = false;
-static mut PROGRESS_POOL: u128 = 0;
-static mut FINAL_POOL: u128 = 0;
-static mut RELEASED_PROGRESS: u128 = 0;
-static mut RELEASED_FINAL: u128 = 0;
-static mut MILESTONE_1_PERCENT: u16 = 0; // Basis points (0-10000)
-
-static mut MILESTONE_2_PERCENT: u16 = 0; // Basis points (0-10000)
-static mut MILESTONE_3_PERCENT: u16 = 0; // Basis points (0-10000)
+static mut ADMIN: [u8; 32] = [0; 32]; // Platform admin: verifier, treasury and fee
+static mut PROJECTS: BTreeMap<ProjectId, Project> = BTreeMap::new();
+static mut NEXT_PROJECT_ID: ProjectId = 1;
+static mut PENDING_CALLS: BTreeMap<ProjectId, u32> = BTreeMap::new(); // Token calls in flight per project
+static mut OWED: BTreeMap<[u8; 32], u128> = BTreeMap::new(); // Released payouts not yet withdrawn, per account
 static mut AUTHORIZED_VERIFIER: [u8; 32] = [0; 32];
 static mut TREASURY: [u8; 32] = [0; 32];
 static mut USDC_TOKEN: [u8; 32] = [0; 32];
+static mut FEE_BPS: u16 = 500; // Platform fee taken from the budget on funding (5%)
+static mut ACCEPTANCE_WINDOW_BLOCKS: u32 = 201_600; // Owner review time before the final pool auto-releases (0 = never)
 
 const BPS_MAX: u128 = 10000;
-const SUCCESS_REPLY: [u8; 1] = [1];
-const FAILURE_REPLY: [u8; 1] = [0];
+const MAX_MILESTONES: usize = 32;
+const MAX_PAYEES: usize = 16;
+const DISPUTE_PERIOD_BLOCKS: u32 = 201_600; // About 7 days of 3-second blocks
+const SHARE_PROPOSAL_PERIOD_BLOCKS: u32 = 201_600; // How long a share proposal stays open for approvals
+
+// Returns early with `$error` when `$cond` does not hold.
+macro_rules! ensure {
+    ($cond:expr, $error:expr) => {
+        if !$cond {
+            return Err($error);
+        }
+    };
+}
+
+// Emits an event by sending it to the zero address, following Gear's event convention.
+fn emit_event(event: EscrowEvent) {
+    msg::send(ActorId::zero(), event, 0).expect("Failed to emit event");
+}
+
+// Returns `bps` basis points of `amount`.
+fn bps_of(amount: u128, bps: u16) -> Result<u128, Error> {
+    Ok(amount.checked_mul(bps as u128).ok_or(Error::Overflow)? / BPS_MAX)
+}
+
+// The maps are reached through raw pointers so no reference to a `static mut` is taken directly.
+unsafe fn projects() -> &'static mut BTreeMap<ProjectId, Project> {
+    &mut *addr_of_mut!(PROJECTS)
+}
+
+unsafe fn pending_calls() -> &'static mut BTreeMap<ProjectId, u32> {
+    &mut *addr_of_mut!(PENDING_CALLS)
+}
+
+unsafe fn owed_balances() -> &'static mut BTreeMap<[u8; 32], u128> {
+    &mut *addr_of_mut!(OWED)
+}
+
+// Looks up a project by id.
+unsafe fn project_mut(project_id: ProjectId) -> Result<&'static mut Project, Error> {
+    projects().get_mut(&project_id).ok_or(Error::NotFound)
+}
+
+// Credits `amount` to what `account` can withdraw from the escrow.
+unsafe fn credit(account: [u8; 32], amount: u128) {
+    *owed_balances().entry(account).or_insert(0) += amount;
+}
+
+// Whether `account` is a member of the project's developer team.
+fn is_payee(project: &Project, account: [u8; 32]) -> bool {
+    project.payees.iter().any(|payee| payee.account == account)
+}
+
+// Checks a developer team: distinct non-zero accounts with positive shares summing to BPS_MAX.
+fn validate_payees(payees: &[Payee]) -> Result<(), Error> {
+    ensure!(!payees.is_empty(), Error::InvalidPayload);
+    ensure!(payees.len() <= MAX_PAYEES, Error::CapacityExceeded);
+    ensure!(
+        payees.iter().all(|payee| payee.share_bps > 0 && payee.account != [0u8; 32]),
+        Error::InvalidPayload
+    );
+    ensure!(
+        payees.iter().map(|payee| payee.share_bps as u128).sum::<u128>() == BPS_MAX,
+        Error::InvalidPayload
+    );
+    let distinct = payees
+        .iter()
+        .enumerate()
+        .all(|(idx, payee)| payees[..idx].iter().all(|other| other.account != payee.account));
+    ensure!(distinct, Error::InvalidPayload);
+    Ok(())
+}
+
+// Divides `amount` among the payees by share; rounding dust goes to the first payee.
+fn split_among(payees: &[Payee], amount: u128) -> Result<Vec<([u8; 32], u128)>, Error> {
+    let (first, rest) = payees.split_first().ok_or(Error::InvalidState)?;
+    let mut parts = vec![(first.account, amount)];
+    for payee in rest {
+        let part = bps_of(amount, payee.share_bps)?;
+        parts[0].1 -= part;
+        parts.push((payee.account, part));
+    }
+    Ok(parts)
+}
+
+// Credits each payee's part of a release from the project.
+unsafe fn credit_parts(project_id: ProjectId, parts: Vec<([u8; 32], u128)>) {
+    for (to, amount) in parts.into_iter().filter(|&(_, amount)| amount > 0) {
+        credit(to, amount);
+        emit_event(EscrowEvent::PaymentReleased { project_id, to, amount });
+    }
+}
+
+// Replaces the team's shares with the pending proposal once the owner and every current payee approved it.
+fn apply_share_proposal(project_id: ProjectId, project: &mut Project) {
+    let Some(proposal) = project.share_proposal.take() else {
+        return;
+    };
+    let approved = |account: &[u8; 32]| proposal.approvals.contains(account);
+    if approved(&project.owner) && project.payees.iter().all(|payee| approved(&payee.account)) {
+        project.payees = proposal.payees.clone();
+        emit_event(EscrowEvent::SharesChanged { project_id, payees: proposal.payees });
+    } else {
+        project.share_proposal = Some(proposal);
+    }
+}
+
+// Returns `amount * numerator / denominator` rounded down, splitting `amount` so the product cannot
+// overflow while `numerator` and `denominator` stay small.
+fn mul_div(amount: u128, numerator: u128, denominator: u128) -> u128 {
+    amount / denominator * numerator + amount % denominator * numerator / denominator
+}
+
+// Computes how much of the progress pool the given milestones of the project have earned.
+fn progress_earned(project: &Project, milestones: &[Milestone]) -> u128 {
+    // Each milestone earns its weight of the pool in proportion to its completion, with the part
+    // completed after its deadline cut by the late penalty, so each milestone's share is out of BPS_MAX^3.
+    let late_rate = BPS_MAX - project.late_penalty_bps as u128;
+    let weighted_total: u128 = milestones
+        .iter()
+        .map(|m| {
+            let late = m.overdue_from.map_or(0, |from| m.percent.saturating_sub(from)) as u128;
+            let on_time = m.percent as u128 - late;
+            m.weight_bps as u128 * (on_time * BPS_MAX + late * late_rate)
+        })
+        .sum();
+    mul_div(project.progress_pool, weighted_total, BPS_MAX * BPS_MAX * BPS_MAX)
+}
+
+// Pushes the deadlines of unfinished milestones back by the time a dispute raised at `raised_at` froze
+// progress reports, and wakes the escrow again at each new deadline.
+unsafe fn extend_deadlines(project_id: ProjectId, project: &mut Project, raised_at: u32) -> Result<(), Error> {
+    let now = exec::block_height();
+    let paused = now.saturating_sub(raised_at);
+    let extended: Vec<(usize, u32)> = project
+        .milestones
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.overdue_from.is_none() && (m.percent as u128) < BPS_MAX)
+        .filter_map(|(idx, m)| m.due_at.filter(|&due_at| due_at >= raised_at).map(|due_at| (idx, due_at)))
+        .map(|(idx, due_at)| (idx, due_at.saturating_add(paused)))
+        .collect();
+
+    for &(idx, due_at) in &extended {
+        let wake_up = EscrowAction::MilestoneDeadline { project_id, milestone_idx: idx as u8 + 1 };
+        msg::send_delayed(exec::program_id(), wake_up, 0, due_at.saturating_sub(now))
+            .map_err(|_| Error::ExternalCallFailed)?;
+    }
+    for (idx, due_at) in extended {
+        project.milestones[idx].due_at = Some(due_at);
+    }
+    Ok(())
+}
+
+// Computes the project's funds still held by the escrow.
+fn held_funds(project: &Project) -> u128 {
+    project
+        .budget
+        .saturating_sub(project.fee)
+        .saturating_sub(project.released_upfront)
+        .saturating_sub(project.released_progress)
+        .saturating_sub(project.released_final)
+        .saturating_sub(project.awarded)
+        .saturating_sub(project.refunded)
+}
+
+// Computes how much of a stream has vested by block `now`.
+fn vested_amount(stream: &Stream, now: u32) -> Result<u128, Error> {
+    if now >= stream.end {
+        return Ok(stream.amount);
+    }
+    let elapsed = now.saturating_sub(stream.start) as u128;
+    let duration = (stream.end - stream.start) as u128;
+    Ok(stream.amount.checked_mul(elapsed).ok_or(Error::Overflow)? / duration)
+}
+
+// Computes what the team can claim from the project's streams at block `now`.
+fn claimable(project: &Project, now: u32) -> Result<u128, Error> {
+    project
+        .streams
+        .iter()
+        .try_fold(0, |total, stream| Ok(total + vested_amount(stream, now)? - stream.claimed))
+}
+
+// Whether a token call made on behalf of the project is still awaiting its reply.
+unsafe fn has_pending_calls(project_id: ProjectId) -> bool {
+    pending_calls().contains_key(&project_id)
+}
+
+// Sends an action to the USDC token and waits for its reply.
+// Forwards the token's own error when it rejects the call.
+async fn call_usdc(action: TokenAction) -> Result<(), Error> {
+    let token = unsafe { ActorId::from(USDC_TOKEN) };
+
+    let reply = match msg::send_for_reply_as::<_, TokenReply>(token, action, 0, 0) {
+        Ok(future) => future.await.map_err(|_| Error::ExternalCallFailed)?,
+        Err(_) => return Err(Error::ExternalCallFailed),
+    };
+
+    match reply {
+        TokenReply::Done => Ok(()),
+        TokenReply::Failed(error) => Err(error),
+        _ => Err(Error::ExternalCallFailed),
+    }
+}
+
+// Sends an action to the USDC token on behalf of a project, counted as pending until it replies.
+async fn call_usdc_for(project_id: ProjectId, action: TokenAction) -> Result<(), Error> {
+    unsafe { *pending_calls().entry(project_id).or_insert(0) += 1 };
+    let result = call_usdc(action).await;
+    unsafe {
+        if let Some(count) = pending_calls().get_mut(&project_id) {
+            *count -= 1;
+            if *count == 0 {
+                pending_calls().remove(&project_id);
+            }
+        }
+    }
+
+    result
+}
+
+// Sends `amount` of the escrow's own USDC, held for the project, to `to`.
+async fn transfer_usdc(project_id: ProjectId, to: [u8; 32], amount: u128) -> Result<(), Error> {
+    call_usdc_for(project_id, TokenAction::Transfer { to, amount }).await
+}
+
+// Pulls `amount` of USDC from `from` into the escrow using the allowance `from` granted it.
+async fn transfer_from_usdc(project_id: ProjectId, from: [u8; 32], amount: u128) -> Result<(), Error> {
+    let to = exec::program_id().into();
+    call_usdc_for(project_id, TokenAction::TransferFrom { from, to, amount }).await
+}
+
+// Closes a funded project early: credits `developer_amount` to the team, refunds `owner_amount`
+// to the owner and leaves the project in the terminal `status`.
+async unsafe fn close_project(
+    project_id: ProjectId,
+    status: ProjectStatus,
+    developer_amount: u128,
+    owner_amount: u128,
+) -> Result<(), Error> {
+    // A refund or pull in flight would make the held amount unknown until it settles.
+    ensure!(!has_pending_calls(project_id), Error::InvalidState);
+
+    let project = project_mut(project_id)?;
+    let previous_status = project.status;
+    let owner = project.owner;
+    // Only projects with a selected team can owe it anything.
+    let parts = if developer_amount > 0 { split_among(&project.payees, developer_amount)? } else { Vec::new() };
+
+    // Commit before awaiting: no other action applies to a closed project.
+    project.status = status;
+    project.awarded += developer_amount;
+    project.refunded += owner_amount;
+
+    credit_parts(project_id, parts);
+
+    if owner_amount > 0 {
+        if let Err(error) = transfer_usdc(project_id, owner, owner_amount).await {
+            // The team keeps what was credited above; closing again only retries the refund.
+            let project = project_mut(project_id)?;
+            project.status = previous_status;
+            project.refunded -= owner_amount;
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+// Cancels a funded project: the team receives what attested progress has earned but not yet
+// been paid, and the owner everything else still held.
+async unsafe fn settle_cancellation(project_id: ProjectId) -> Result<(), Error> {
+    let project = project_mut(project_id)?;
+    let developer_payout = progress_earned(project, &project.milestones)
+        .saturating_sub(project.released_progress)
+        .saturating_sub(project.awarded);
+    let refund = held_funds(project).saturating_sub(developer_payout);
+
+    close_project(project_id, ProjectStatus::Cancelled, developer_payout, refund).await?;
+
+    emit_event(EscrowEvent::ProjectCancelled { project_id, refund, developer_payout });
+    Ok(())
+}
+
+// Completes the project: credits the final pool to the team and progress forfeited to late
+// penalties to the owner, so neither payout depends on the other's transfer.
+unsafe fn release_final(project_id: ProjectId) -> Result<(), Error> {
+    let project = project_mut(project_id)?;
+    ensure!(project.status == ProjectStatus::InProgress, Error::InvalidState);
+    ensure!(project.dispute.is_none(), Error::DisputeOpen);
+    ensure!(
+        project.milestones.iter().all(|m| m.percent as u128 == BPS_MAX),
+        Error::InvalidState
+    );
+    let owner = project.owner;
+    let amount = project.final_pool;
+    let mut parts = split_among(&project.payees, amount)?;
+    let forfeited = held_funds(project).saturating_sub(amount);
+    parts.push((owner, forfeited));
+
+    project.released_final = amount;
+    project.refunded += forfeited;
+    project.status = ProjectStatus::Completed;
+
+    credit_parts(project_id, parts);
+    emit_event(EscrowEvent::FinalApproved { project_id, amount });
+    Ok(())
+}
 
 #[no_mangle]
 extern "C" fn init() {
-    let payload = msg::load_bytes().expect("Failed to load init payload");
-    gstd::assert!(payload.len() == 64, "Invalid init payload length");
+    let init: EscrowInit = msg::load().expect("Invalid init payload");
 
     unsafe {
-        OWNER = *msg::source().as_ref();
-        TREASURY.copy_from_slice(&payload[0..32]);
-        USDC_TOKEN.copy_from_slice(&payload[32..64]);
+        ADMIN = msg::source().into();
+        TREASURY = init.treasury;
+        USDC_TOKEN = init.usdc_token;
     }
     // A real contract would likely verify these addresses are valid contracts.
 
-    msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Failed to reply in init");
+    msg::reply(EscrowReply::Done, 0).expect("Failed to reply in init");
 }
 
-#[no_mangle]
-extern "C" fn handle() {
-    let cmd_bytes = msg::load_bytes().expect("Failed to load command bytes");
-    gstd::assert!(!cmd_bytes.is_empty(), "Command cannot be empty");
+#[gstd::async_main]
+async fn main() {
+    // Load and decode the incoming action; trailing bytes are rejected.
+    let payload = msg::load_bytes().expect("Failed to load command bytes");
+    let reply = match decode_action::<EscrowAction>(&payload) {
+        Ok(action) => unsafe { process(action, msg::source().into()).await },
+        Err(error) => Err(error),
+    };
 
-    let source = msg::source();
-    let source_bytes = *source.as_ref();
+    msg::reply(reply.unwrap_or_else(EscrowReply::Failed), 0).expect("Reply failed");
+}
 
-    unsafe {
-        match cmd_bytes[0] {
-            // 1. CREATE_PROJECT [1, budget(16 LE)]
-            1 => {
-                gstd::assert_eq!(source_bytes, OWNER, "Only owner can create a project");
-                gstd::assert!(!IS_ACTIVE, "Project already created");
-                gstd::assert!(cmd_bytes.len() == 17, "Invalid payload for CREATE_PROJECT");
+// Executes a single action and returns the reply to send.
+//
+// Project entries are looked up again after every await: other messages may have
+// modified the registry while this one was waiting for the token.
+async unsafe fn process(action: EscrowAction, source_bytes: [u8; 32]) -> Result<EscrowReply, Error> {
+    match action {
+        EscrowAction::CreateProject { budget, milestones, split, late_penalty_bps, stream_blocks } => {
+            ensure!(budget > 0, Error::InvalidPayload);
+            ensure!(!milestones.is_empty(), Error::InvalidPayload);
+            ensure!(milestones.len() <= MAX_MILESTONES, Error::CapacityExceeded);
+            ensure!(milestones.iter().all(|m| m.weight_bps > 0), Error::InvalidPayload);
+            ensure!(
+                milestones.iter().map(|m| m.weight_bps as u128).sum::<u128>() == BPS_MAX,
+                Error::InvalidPayload
+            );
+            ensure!(late_penalty_bps as u128 <= BPS_MAX, Error::InvalidPayload);
+            let PaymentSplit { upfront_bps, progress_bps, final_bps } = split;
+            ensure!(
+                upfront_bps as u128 + progress_bps as u128 + final_bps as u128 == BPS_MAX,
+                Error::InvalidPayload
+            );
+
+            let project_id = NEXT_PROJECT_ID;
+            NEXT_PROJECT_ID = project_id.checked_add(1).ok_or(Error::Overflow)?;
+
+            projects().insert(
+                project_id,
+                Project {
+                    owner: source_bytes,
+                    payees: Vec::new(),
+                    budget,
+                    fee: 0,
+                    status: ProjectStatus::Created,
+                    split,
+                    late_penalty_bps,
+                    stream_blocks,
+                    upfront_pool: 0,
+                    progress_pool: 0,
+                    final_pool: 0,
+                    released_upfront: 0,
+                    released_progress: 0,
+                    released_final: 0,
+                    awarded: 0,
+                    refunded: 0,
+                    milestones: milestones
+                        .into_iter()
+                        .map(|MilestoneSpec { weight_bps, deadline_blocks }| Milestone {
+                            weight_bps,
+                            deadline_blocks,
+                            due_at: None,
+                            percent: 0,
+                            overdue_from: None,
+                        })
+                        .collect(),
+                    cancel_approvals: Vec::new(),
+                    arbiter: None,
+                    dispute: None,
+                    acceptance_deadline: None,
+                    streams: Vec::new(),
+                    share_proposal: None,
+                },
+            );
+
+            emit_event(EscrowEvent::ProjectCreated { project_id, owner: source_bytes, budget });
+
+            return Ok(EscrowReply::ProjectCreated { project_id });
+        }
 
-                let budget_bytes: [u8; 16] = cmd_bytes[1..17]
-                    .try_into()
-                    .expect("Invalid budget bytes");
-                BUDGET = u128::from_le_bytes(budget_bytes);
-                gstd::assert!(BUDGET > 0, "Budget must be greater than zero");
-                IS_ACTIVE = true;
+        // The owner must first approve the escrow on USDC_TOKEN for at least the budget.
+        EscrowAction::FundProject { project_id } => {
+            let project = project_mut(project_id)?;
+            ensure!(source_bytes == project.owner, Error::Unauthorized);
+            ensure!(project.status == ProjectStatus::Created, Error::InvalidState);
+
+            let owner = project.owner;
+            let budget = project.budget;
+            let fee = bps_of(budget, FEE_BPS)?;
+            let net_budget = budget - fee;
+
+            // The net budget is divided as negotiated for this project.
+            let upfront_pool = bps_of(net_budget, project.split.upfront_bps)?;
+            let progress_pool = bps_of(net_budget, project.split.progress_bps)?;
+            let final_pool = bps_of(net_budget, project.split.final_bps)?;
+
+            // Mark as funding before awaiting: a concurrent FUND_PROJECT cannot pull twice and nothing
+            // can pay out of the pools until the budget has arrived.
+            project.status = ProjectStatus::Funding;
+            project.fee = fee;
+            project.upfront_pool = upfront_pool;
+            project.progress_pool = progress_pool;
+            project.final_pool = final_pool;
+
+            let mut result = transfer_from_usdc(project_id, owner, budget).await;
+            if result.is_ok() && fee > 0 {
+                result = transfer_usdc(project_id, TREASURY, fee).await;
+                if result.is_err() && transfer_usdc(project_id, owner, budget).await.is_err() {
+                    // The fee could not be routed and neither could the budget be returned:
+                    // the owner withdraws it instead.
+                    credit(owner, budget);
+                    emit_event(EscrowEvent::PaymentReleased { project_id, to: owner, amount: budget });
+                }
+            }
 
-                msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
+            // Only settle the status this pull set; nothing else should have moved it meanwhile.
+            let project = project_mut(project_id)?;
+            let still_funding = project.status == ProjectStatus::Funding;
+            if let Err(error) = result {
+                // The project stays unfunded.
+                if still_funding {
+                    project.status = ProjectStatus::Created;
+                    project.fee = 0;
+                    project.upfront_pool = 0;
+                    project.progress_pool = 0;
+                    project.final_pool = 0;
+                }
+                return Err(error);
+            }
+            if still_funding {
+                project.status = ProjectStatus::Funded;
             }
 
-            // 2. FUND_PROJECT [2]
-            2 => {
-                gstd::assert_eq!(source_bytes, OWNER, "Only owner can fund the project");
-                gstd::assert!(IS_ACTIVE, "Project is not active");
-                gstd::assert!(!IS_FUNDED, "Project is already funded");
+            emit_event(EscrowEvent::ProjectFunded {
+                project_id,
+                budget,
+                fee,
+                upfront_pool,
+                progress_pool,
+                final_pool,
+            });
+        }
 
-                let fee = BUDGET.checked_mul(5).unwrap().checked_div(100).unwrap();
-                let net_budget = BUDGET.checked_sub(fee).unwrap();
+        EscrowAction::SelectDeveloper { project_id, payees } => {
+            validate_payees(&payees)?;
+            let project = project_mut(project_id)?;
+            ensure!(source_bytes == project.owner, Error::Unauthorized);
+            ensure!(project.status == ProjectStatus::Funded, Error::InvalidState);
+            ensure!(payees.iter().all(|payee| Some(payee.account) != project.arbiter), Error::InvalidPayload);
+            let upfront = project.upfront_pool;
+            let parts = split_among(&payees, upfront)?;
+
+            // Deadlines run from selection. Each one wakes the escrow with a delayed self-message.
+            for (idx, milestone) in project.milestones.iter().enumerate() {
+                if milestone.deadline_blocks > 0 {
+                    let wake_up = EscrowAction::MilestoneDeadline { project_id, milestone_idx: idx as u8 + 1 };
+                    msg::send_delayed(exec::program_id(), wake_up, 0, milestone.deadline_blocks)
+                        .map_err(|_| Error::ExternalCallFailed)?;
+                }
+            }
+            let now = exec::block_height();
+            for milestone in project.milestones.iter_mut().filter(|m| m.deadline_blocks > 0) {
+                milestone.due_at = Some(now.saturating_add(milestone.deadline_blocks));
+            }
 
-                // 60% of net budget for progress milestones
-                PROGRESS_POOL = net_budget.checked_mul(60).unwrap().checked_div(100).unwrap();
-                // 35% of net budget for final payment
-                FINAL_POOL = net_budget.checked_mul(35).unwrap().checked_div(100).unwrap();
+            project.payees = payees.clone();
+            project.status = ProjectStatus::InProgress;
+            project.released_upfront = upfront;
 
-                IS_FUNDED = true;
+            emit_event(EscrowEvent::DeveloperSelected { project_id, payees });
+            credit_parts(project_id, parts);
+        }
 
-                msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
+        EscrowAction::ApplyProgress { project_id, milestone_idx, percent: new_percent } => {
+            ensure!(source_bytes == AUTHORIZED_VERIFIER, Error::Unauthorized);
+            ensure!(new_percent as u128 <= BPS_MAX, Error::InvalidPayload);
+
+            let project = project_mut(project_id)?;
+            ensure!(project.status == ProjectStatus::InProgress, Error::InvalidState);
+            ensure!(project.dispute.is_none(), Error::DisputeOpen);
+
+            // Milestones are addressed from 1.
+            let slot = (milestone_idx as usize).checked_sub(1).ok_or(Error::NotFound)?;
+            let milestone = project.milestones.get(slot).ok_or(Error::NotFound)?;
+
+            // Non-monotonic updates are rejected without changing state.
+            ensure!(
+                new_percent > milestone.percent,
+                Error::PolicyViolation { reason: PolicyReason::NotMonotonic }
+            );
+
+            // The payout is worked out on the updated milestones before any state changes, so a
+            // failure leaves the project as it was.
+            let mut milestones = project.milestones.clone();
+            milestones[slot].percent = new_percent;
+            let payout_delta = progress_earned(project, &milestones).saturating_sub(project.released_progress);
+            let parts = if payout_delta > 0 && project.stream_blocks == 0 {
+                split_among(&project.payees, payout_delta)?
+            } else {
+                Vec::new()
+            };
+
+            // Completing the last milestone opens the acceptance window, closed by a delayed self-message.
+            let completes_project = milestones.iter().all(|m| m.percent as u128 == BPS_MAX);
+            let acceptance_deadline = if completes_project && ACCEPTANCE_WINDOW_BLOCKS > 0 {
+                let wake_up = EscrowAction::AutoApprove { project_id };
+                msg::send_delayed(exec::program_id(), wake_up, 0, ACCEPTANCE_WINDOW_BLOCKS)
+                    .map_err(|_| Error::ExternalCallFailed)?;
+                Some(exec::block_height().saturating_add(ACCEPTANCE_WINDOW_BLOCKS))
+            } else {
+                None
+            };
+
+            project.milestones = milestones;
+            if acceptance_deadline.is_some() {
+                project.acceptance_deadline = acceptance_deadline;
             }
 
-            // 3. SELECT_DEVELOPER [3, dev(32)]
-            3 => {
-                gstd::assert_eq!(source_bytes, OWNER, "Only owner can select a developer");
-                gstd::assert!(IS_FUNDED, "Project must be funded first");
-                gstd::assert!(!DEVELOPER_SELECTED, "Developer already selected");
-                gstd::assert!(cmd_bytes.len() == 33, "Invalid payload for SELECT_DEVELOPER");
+            if payout_delta > 0 {
+                project.released_progress += payout_delta;
 
-                DEVELOPER.copy_from_slice(&cmd_bytes[1..33]);
-                DEVELOPER_SELECTED = true;
+                if project.stream_blocks > 0 {
+                    // Streaming projects vest the delta instead of crediting it; the team claims it over time.
+                    let start = exec::block_height();
+                    let end = start.saturating_add(project.stream_blocks);
+                    project.streams.push(Stream { amount: payout_delta, start, end, claimed: 0 });
 
-                msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
+                    emit_event(EscrowEvent::StreamStarted { project_id, amount: payout_delta, start, end });
+                } else {
+                    credit_parts(project_id, parts);
+                }
+            }
+            emit_event(EscrowEvent::ProgressApplied {
+                project_id,
+                milestone_idx,
+                percent: new_percent,
+                payout: payout_delta,
+            });
+            if let Some(deadline) = acceptance_deadline {
+                emit_event(EscrowEvent::AwaitingAcceptance { project_id, deadline });
             }
+        }
 
-            // 4. APPLY_PROGRESS [4, milestone_idx(1), new_percent(2 LE)]
-            4 => {
-                gstd::assert_eq!(source_bytes, AUTHORIZED_VERIFIER, "Only the verifier can apply progress");
-                gstd::assert!(DEVELOPER_SELECTED, "Developer must be selected");
-                gstd::assert!(cmd_bytes.len() == 4, "Invalid payload for APPLY_PROGRESS");
+        EscrowAction::MarkFinalApproved { project_id } => {
+            let project = project_mut(project_id)?;
+            let window_passed = project.acceptance_deadline.is_some_and(|deadline| exec::block_height() >= deadline);
+            ensure!(source_bytes == project.owner || window_passed, Error::Unauthorized);
 
-                let milestone_idx = cmd_bytes[1];
-                let percent_bytes: [u8; 2] = cmd_bytes[2..4].try_into().expect("Invalid percent bytes");
-                let new_percent = u16::from_le_bytes(percent_bytes);
-                gstd::assert!(new_percent <= BPS_MAX as u16, "Percentage cannot exceed 10000 bps");
+            release_final(project_id)?;
+        }
 
-                let current_total_percent = MILESTONE_1_PERCENT as u128 + MILESTONE_2_PERCENT as u128 + MILESTONE_3_PERCENT as u128;
+        EscrowAction::SetVerifier { verifier } => {
+            ensure!(source_bytes == ADMIN, Error::Unauthorized);
 
-                let mut update_successful = false;
+            AUTHORIZED_VERIFIER = verifier;
+        }
 
-                match milestone_idx {
-                    1 => {
-                        if new_percent > MILESTONE_1_PERCENT {
-                            MILESTONE_1_PERCENT = new_percent;
-                            update_successful = true;
-                        }
-                    }
-                    2 => {
-                        if new_percent > MILESTONE_2_PERCENT {
-                            MILESTONE_2_PERCENT = new_percent;
-                            update_successful = true;
+        EscrowAction::SetTreasury { treasury } => {
+            ensure!(source_bytes == ADMIN, Error::Unauthorized);
+            ensure!(treasury != [0u8; 32], Error::InvalidPayload);
+
+            TREASURY = treasury;
+        }
+
+        EscrowAction::SetFeeBps { fee_bps } => {
+            ensure!(source_bytes == ADMIN, Error::Unauthorized);
+            ensure!(fee_bps as u128 <= BPS_MAX, Error::InvalidPayload);
+
+            FEE_BPS = fee_bps;
+        }
+
+        EscrowAction::GetProject { project_id } => {
+            let project = project_mut(project_id)?;
+
+            return Ok(EscrowReply::Project(Box::new(project.clone())));
+        }
+
+        EscrowAction::CancelProject { project_id } => {
+            let project = project_mut(project_id)?;
+            let is_owner = source_bytes == project.owner;
+
+            match project.status {
+                // Nothing has been pulled from the owner yet.
+                ProjectStatus::Created => {
+                    ensure!(is_owner, Error::Unauthorized);
+
+                    project.status = ProjectStatus::Cancelled;
+                    emit_event(EscrowEvent::ProjectCancelled { project_id, refund: 0, developer_payout: 0 });
+                }
+
+                ProjectStatus::Funded => {
+                    ensure!(is_owner, Error::Unauthorized);
+
+                    settle_cancellation(project_id).await?;
+                }
+
+                // Once a team is engaged, the owner and every payee must agree unless the platform arbitrates.
+                ProjectStatus::InProgress => {
+                    let is_admin = source_bytes == ADMIN;
+                    ensure!(is_owner || is_payee(project, source_bytes) || is_admin, Error::Unauthorized);
+                    ensure!(project.dispute.is_none(), Error::DisputeOpen);
+
+                    // The owner may also walk away alone while an overdue milestone is unfinished, but not
+                    // once the work has been delivered for acceptance.
+                    let overdue = project
+                        .milestones
+                        .iter()
+                        .any(|m| m.overdue_from.is_some() && (m.percent as u128) < BPS_MAX);
+                    let owner_walks_away = is_owner && overdue && project.acceptance_deadline.is_none();
+                    if !is_admin && !owner_walks_away {
+                        if !project.cancel_approvals.contains(&source_bytes) {
+                            project.cancel_approvals.push(source_bytes);
                         }
-                    }
-                    3 => {
-                        if new_percent > MILESTONE_3_PERCENT {
-                            MILESTONE_3_PERCENT = new_percent;
-                            update_successful = true;
+                        let approved = |account: &[u8; 32]| project.cancel_approvals.contains(account);
+                        if !approved(&project.owner) || !project.payees.iter().all(|payee| approved(&payee.account)) {
+                            emit_event(EscrowEvent::CancellationRequested { project_id, by: source_bytes });
+                            return Ok(EscrowReply::Done);
                         }
                     }
-                    _ => gstd::panic!("Invalid milestone index"),
-                }
 
-                if update_successful {
-                    let new_total_percent = MILESTONE_1_PERCENT as u128 + MILESTONE_2_PERCENT as u128 + MILESTONE_3_PERCENT as u128;
-                    // Total percent is out of 30000 bps (3 * 10000)
-                    let total_releasable = PROGRESS_POOL.checked_mul(new_total_percent).unwrap()
-                        .checked_div(BPS_MAX * 3).unwrap();
-                    
-                    let payout_delta = total_releasable.checked_sub(RELEASED_PROGRESS).unwrap();
-
-                    if payout_delta > 0 {
-                        RELEASED_PROGRESS = RELEASED_PROGRESS.checked_add(payout_delta).unwrap();
-                        // In a real contract, this would trigger a gclient::transfer call to USDC_TOKEN
-                        // to send `payout_delta` to `DEVELOPER`.
-                    }
-                    msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
-                } else {
-                    // Non-monotonic update is not an error, but we reply failure.
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
+                    settle_cancellation(project_id).await?;
                 }
+
+                // The budget pull is still in flight.
+                ProjectStatus::Funding
+                | ProjectStatus::Completed
+                | ProjectStatus::Cancelled
+                | ProjectStatus::Resolved => return Err(Error::InvalidState),
             }
+        }
+
+        EscrowAction::RaiseDispute { project_id } => {
+            let project = project_mut(project_id)?;
+            let is_party = source_bytes == project.owner || is_payee(project, source_bytes);
+            ensure!(is_party, Error::Unauthorized);
+            ensure!(project.status == ProjectStatus::InProgress, Error::InvalidState);
+            ensure!(project.dispute.is_none(), Error::DisputeOpen);
+
+            let raised_at = exec::block_height();
+            let deadline = raised_at.saturating_add(DISPUTE_PERIOD_BLOCKS);
+            project.dispute = Some(Dispute { raised_by: source_bytes, raised_at, deadline });
+
+            emit_event(EscrowEvent::DisputeRaised { project_id, by: source_bytes, deadline });
+        }
+
+        EscrowAction::WithdrawDispute { project_id } => {
+            let project = project_mut(project_id)?;
+            let dispute = project.dispute.as_ref().ok_or(Error::NotFound)?;
+            ensure!(source_bytes == dispute.raised_by, Error::Unauthorized);
+
+            extend_deadlines(project_id, project, dispute.raised_at)?;
+            project.dispute = None;
+
+            emit_event(EscrowEvent::DisputeWithdrawn { project_id });
+        }
+
+        EscrowAction::ExpireDispute { project_id } => {
+            let project = project_mut(project_id)?;
+            let dispute = project.dispute.as_ref().ok_or(Error::NotFound)?;
+            ensure!(exec::block_height() > dispute.deadline, Error::InvalidState);
+
+            extend_deadlines(project_id, project, dispute.raised_at)?;
+            project.dispute = None;
+
+            emit_event(EscrowEvent::DisputeExpired { project_id });
+        }
+
+        EscrowAction::SetArbiter { project_id, arbiter } => {
+            let project = project_mut(project_id)?;
+            let is_admin = source_bytes == ADMIN;
+            ensure!(is_admin || source_bytes == project.owner, Error::Unauthorized);
+            // The owner cannot swap the arbiter once a dispute is underway.
+            ensure!(is_admin || project.dispute.is_none(), Error::DisputeOpen);
+            // The team accepts the project with the arbiter in place; the owner cannot swap it afterwards.
+            ensure!(
+                is_admin
+                    || matches!(
+                        project.status,
+                        ProjectStatus::Created | ProjectStatus::Funding | ProjectStatus::Funded
+                    ),
+                Error::InvalidState
+            );
+            // Neither side may rule on its own dispute.
+            ensure!(
+                arbiter != [0u8; 32] && arbiter != project.owner && !is_payee(project, arbiter),
+                Error::InvalidPayload
+            );
+
+            project.arbiter = Some(arbiter);
+
+            emit_event(EscrowEvent::ArbiterSet { project_id, arbiter });
+        }
 
-            // 5. MARK_FINAL_APPROVED [5]
-            5 => {
-                gstd::assert_eq!(source_bytes, OWNER, "Only owner can mark as final");
-                gstd::assert!(RELEASED_FINAL == 0, "Final payment already released");
-                gstd::assert!(MILESTONE_1_PERCENT == BPS_MAX as u16, "Milestone 1 not complete");
-                gstd::assert!(MILESTONE_2_PERCENT == BPS_MAX as u16, "Milestone 2 not complete");
-                gstd::assert!(MILESTONE_3_PERCENT == BPS_MAX as u16, "Milestone 3 not complete");
+        EscrowAction::ResolveDispute { project_id, developer_bps } => {
+            let project = project_mut(project_id)?;
+            ensure!(source_bytes == project.arbiter.unwrap_or(ADMIN), Error::Unauthorized);
+            let dispute = project.dispute.as_ref().ok_or(Error::NotFound)?;
+            ensure!(exec::block_height() <= dispute.deadline, Error::InvalidState);
+            ensure!(developer_bps as u128 <= BPS_MAX, Error::InvalidPayload);
+            ensure!(!has_pending_calls(project_id), Error::InvalidState);
 
-                RELEASED_FINAL = FINAL_POOL;
-                // In a real contract, this would trigger a gclient::transfer call to USDC_TOKEN
-                // to send `FINAL_POOL` to `DEVELOPER`.
+            // What the streams have not paid out yet is still held and split by the ruling too.
+            let unclaimed: u128 = project.streams.iter().map(|stream| stream.amount - stream.claimed).sum();
+            project.released_progress -= unclaimed;
+            project.streams.clear();
 
-                msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
+            // A previous attempt may already have credited the team's share before the refund failed.
+            let pot = held_funds(project) + project.awarded;
+            let developer_amount = bps_of(pot, developer_bps)?.saturating_sub(project.awarded);
+            let owner_amount = held_funds(project).saturating_sub(developer_amount);
+
+            close_project(project_id, ProjectStatus::Resolved, developer_amount, owner_amount).await?;
+
+            let project = project_mut(project_id)?;
+            project.dispute = None;
+
+            emit_event(EscrowEvent::DisputeResolved { project_id, developer_amount, owner_amount });
+        }
+
+        EscrowAction::SetAcceptanceWindow { blocks } => {
+            ensure!(source_bytes == ADMIN, Error::Unauthorized);
+
+            ACCEPTANCE_WINDOW_BLOCKS = blocks;
+        }
+
+        EscrowAction::RejectDelivery { project_id } => {
+            let project = project_mut(project_id)?;
+            ensure!(source_bytes == project.owner, Error::Unauthorized);
+            ensure!(project.status == ProjectStatus::InProgress, Error::InvalidState);
+            let deadline = project.acceptance_deadline.ok_or(Error::InvalidState)?;
+            ensure!(exec::block_height() < deadline, Error::InvalidState);
+
+            project.acceptance_deadline = None;
+
+            emit_event(EscrowEvent::DeliveryRejected { project_id });
+        }
+
+        EscrowAction::AutoApprove { project_id } => {
+            ensure!(source_bytes == <[u8; 32]>::from(exec::program_id()), Error::Unauthorized);
+
+            // Stale wake-ups and frozen projects are left alone; once a dispute closes after the
+            // window, anyone can release the final pool with MARK_FINAL_APPROVED.
+            let project = project_mut(project_id)?;
+            let due = project.acceptance_deadline.is_some_and(|deadline| exec::block_height() >= deadline);
+            if !due || project.status != ProjectStatus::InProgress || project.dispute.is_some() {
+                return Ok(EscrowReply::Done);
             }
 
-            // 6. SET_VERIFIER [6, verifier(32)]
-            6 => {
-                gstd::assert_eq!(source_bytes, OWNER, "Only owner can set the verifier");
-                gstd::assert!(cmd_bytes.len() == 33, "Invalid payload for SET_VERIFIER");
+            release_final(project_id)?;
+        }
+
+        EscrowAction::Claim { project_id } => {
+            let project = project_mut(project_id)?;
+            ensure!(is_payee(project, source_bytes), Error::Unauthorized);
+            ensure!(project.dispute.is_none(), Error::DisputeOpen);
+
+            let now = exec::block_height();
+            let amount = claimable(project, now)?;
+            ensure!(amount > 0, Error::InsufficientBalance);
+            let parts = split_among(&project.payees, amount)?;
+
+            for stream in project.streams.iter_mut() {
+                stream.claimed = vested_amount(stream, now)?;
+            }
+            project.streams.retain(|stream| stream.claimed < stream.amount);
+            credit_parts(project_id, parts);
+        }
+
+        EscrowAction::GetClaimable { project_id } => {
+            let project = project_mut(project_id)?;
+
+            return Ok(EscrowReply::Claimable(claimable(project, exec::block_height())?));
+        }
 
-                AUTHORIZED_VERIFIER.copy_from_slice(&cmd_bytes[1..33]);
+        EscrowAction::Withdraw { amount } => {
+            ensure!(amount > 0, Error::InvalidPayload);
+            let owed = owed_balances().get(&source_bytes).copied().unwrap_or(0);
+            ensure!(amount <= owed, Error::InsufficientBalance);
 
-                msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
+            // Debit before awaiting so a concurrent WITHDRAW cannot pay out the same funds twice.
+            if amount == owed {
+                owed_balances().remove(&source_bytes);
+            } else {
+                owed_balances().insert(source_bytes, owed - amount);
             }
 
-            _ => {
-                gstd::panic!("Unknown command");
+            if let Err(error) = call_usdc(TokenAction::Transfer { to: source_bytes, amount }).await {
+                credit(source_bytes, amount);
+                return Err(error);
+            }
+
+            emit_event(EscrowEvent::Withdrawn { to: source_bytes, amount });
+        }
+
+        EscrowAction::GetOwed { account } => {
+            return Ok(EscrowReply::Owed(owed_balances().get(&account).copied().unwrap_or(0)));
+        }
+
+        EscrowAction::ProposeShares { project_id, payees } => {
+            validate_payees(&payees)?;
+            let project = project_mut(project_id)?;
+            ensure!(source_bytes == project.owner || is_payee(project, source_bytes), Error::Unauthorized);
+            ensure!(project.status == ProjectStatus::InProgress, Error::InvalidState);
+            ensure!(payees.iter().all(|payee| Some(payee.account) != project.arbiter), Error::InvalidPayload);
+            // A pending proposal is only replaced once it has expired or its proposer withdrew it.
+            let now = exec::block_height();
+            ensure!(
+                project.share_proposal.as_ref().is_none_or(|proposal| now >= proposal.expires_at),
+                Error::InvalidState
+            );
+
+            project.share_proposal = Some(ShareProposal {
+                payees: payees.clone(),
+                approvals: vec![source_bytes],
+                proposed_by: source_bytes,
+                expires_at: now.saturating_add(SHARE_PROPOSAL_PERIOD_BLOCKS),
+            });
+
+            emit_event(EscrowEvent::SharesProposed { project_id, by: source_bytes, payees });
+            apply_share_proposal(project_id, project);
+        }
+
+        EscrowAction::ApproveShares { project_id } => {
+            let project = project_mut(project_id)?;
+            ensure!(source_bytes == project.owner || is_payee(project, source_bytes), Error::Unauthorized);
+            ensure!(project.status == ProjectStatus::InProgress, Error::InvalidState);
+            let proposal = project.share_proposal.as_mut().ok_or(Error::NotFound)?;
+            ensure!(exec::block_height() < proposal.expires_at, Error::InvalidState);
+            ensure!(!proposal.approvals.contains(&source_bytes), Error::InvalidState);
+
+            proposal.approvals.push(source_bytes);
+
+            emit_event(EscrowEvent::SharesApproved { project_id, by: source_bytes });
+            apply_share_proposal(project_id, project);
+        }
+
+        EscrowAction::WithdrawShares { project_id } => {
+            let project = project_mut(project_id)?;
+            let proposal = project.share_proposal.as_ref().ok_or(Error::NotFound)?;
+            ensure!(source_bytes == proposal.proposed_by, Error::Unauthorized);
+
+            project.share_proposal = None;
+
+            emit_event(EscrowEvent::SharesWithdrawn { project_id });
+        }
+
+        EscrowAction::MilestoneDeadline { project_id, milestone_idx } => {
+            ensure!(source_bytes == <[u8; 32]>::from(exec::program_id()), Error::Unauthorized);
+
+            // Progress reports are frozen during a dispute, so closing it pushes the deadline back instead.
+            let project = project_mut(project_id)?;
+            if project.status != ProjectStatus::InProgress || project.dispute.is_some() {
+                return Ok(EscrowReply::Done);
+            }
+            let slot = (milestone_idx as usize).checked_sub(1).ok_or(Error::NotFound)?;
+            let milestone = project.milestones.get_mut(slot).ok_or(Error::NotFound)?;
+
+            let due = milestone.due_at.is_some_and(|due_at| exec::block_height() >= due_at);
+            if due && milestone.overdue_from.is_none() && (milestone.percent as u128) < BPS_MAX {
+                milestone.overdue_from = Some(milestone.percent);
+                emit_event(EscrowEvent::MilestoneOverdue { project_id, milestone_idx, percent: milestone.percent });
             }
         }
     }
-}
\ No newline at end of file
+
+    Ok(EscrowReply::Done)
+}
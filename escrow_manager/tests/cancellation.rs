@@ -0,0 +1,45 @@
+//! Cancelling an in-progress project, run against the escrow's WASM.
+
+mod common;
+
+use common::*;
+use gtest::System;
+use hvs_io::{EscrowAction, EscrowReply, MilestoneSpec, ProjectStatus};
+
+#[test]
+fn completing_an_overdue_milestone_ends_the_owners_lone_cancel() {
+    let sys = System::new();
+    let (token, escrow) = deploy(&sys);
+    let milestones = vec![
+        MilestoneSpec { weight_bps: 5000, deadline_blocks: 5 },
+        MilestoneSpec { weight_bps: 5000, deadline_blocks: 0 },
+    ];
+    let project_id = fund_project(&sys, &token, &escrow, milestones, 0);
+    select_developer(&sys, &escrow, project_id);
+
+    // Half of the first milestone lands before its deadline and the rest after it.
+    apply_progress(&sys, &escrow, project_id, 1, 5000);
+    run_blocks(&sys, 5);
+    assert_eq!(project(&sys, &escrow, project_id).milestones[0].overdue_from, Some(5000));
+    apply_progress(&sys, &escrow, project_id, 1, 10000);
+
+    // The late half earns 90%: 38_000 * 50% * (50% + 50% * 90%).
+    let earned = 18_050;
+    assert_eq!(project(&sys, &escrow, project_id).released_progress, earned);
+
+    // Nothing overdue is left unfinished, so the owner alone only records their agreement.
+    let cancel = EscrowAction::CancelProject { project_id };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, OWNER, cancel.clone()), EscrowReply::Done);
+    let state = project(&sys, &escrow, project_id);
+    assert_eq!(state.status, ProjectStatus::InProgress);
+    assert_eq!(state.cancel_approvals, vec![account(OWNER)]);
+
+    // Once the developer agrees too, the owner gets back everything that was not earned.
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, DEV, cancel), EscrowReply::Done);
+    let refund = BUDGET - BUDGET / 20 - UPFRONT_POOL - earned;
+    let state = project(&sys, &escrow, project_id);
+    assert_eq!(state.status, ProjectStatus::Cancelled);
+    assert_eq!(state.refunded, refund);
+    assert_eq!(balance(&sys, &token, OWNER), refund);
+    assert_eq!(owed(&sys, &escrow, DEV), UPFRONT_POOL + earned);
+}
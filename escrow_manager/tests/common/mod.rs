@@ -0,0 +1,128 @@
+//! Deploys the token and the escrow in a gtest `System` and drives them the way their users do.
+//!
+//! The token is loaded from its own build, so run `cargo build --release` in `usdc_token/` first.
+
+#![allow(dead_code)]
+
+use gstd::ActorId;
+use gtest::{Program, System};
+use hvs_io::{
+    Decode, Encode, EscrowAction, EscrowInit, EscrowReply, MilestoneSpec, Payee, PaymentSplit, Project, ProjectId,
+    TokenAction, TokenReply,
+};
+
+pub const ADMIN: u64 = 10;
+pub const OWNER: u64 = 11;
+pub const DEV: u64 = 12;
+pub const VERIFIER: u64 = 13;
+pub const ARBITER: u64 = 14;
+pub const TREASURY: u64 = 15;
+
+/// Budget the owner is minted and funds; 5% goes to the treasury and the rest is split 20/40/40.
+pub const BUDGET: u128 = 100_000;
+pub const UPFRONT_POOL: u128 = 19_000;
+
+const TOKEN_WASM: &str = "../usdc_token/target/wasm32-unknown-unknown/release/usdc_token.opt.wasm";
+// Covers the gas of every message a test user sends.
+const GAS_FUNDS: u128 = 1_000_000_000_000_000;
+
+pub fn account(id: u64) -> [u8; 32] {
+    ActorId::from(id).into()
+}
+
+/// Sends `payload` from `from`, runs the block and returns the program's decoded reply.
+pub fn call<R: Decode>(sys: &System, program: &Program<'_>, from: u64, payload: impl Encode) -> R {
+    let message_id = program.send_bytes(from, payload.encode());
+    let result = sys.run_next_block();
+    let reply = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(message_id))
+        .expect("the program did not reply");
+    R::decode(&mut reply.payload()).expect("the reply does not decode")
+}
+
+/// Runs `blocks` blocks, delivering the delayed messages that fall due in them.
+pub fn run_blocks(sys: &System, blocks: u32) {
+    for _ in 0..blocks {
+        sys.run_next_block();
+    }
+}
+
+/// Deploys the token and the escrow, mints the budget to `OWNER` and makes `VERIFIER` the escrow's verifier.
+pub fn deploy(sys: &System) -> (Program<'_>, Program<'_>) {
+    for user in [ADMIN, OWNER, DEV, VERIFIER, ARBITER] {
+        sys.mint_to(user, GAS_FUNDS);
+    }
+
+    let token = Program::from_file(sys, TOKEN_WASM);
+    assert_eq!(call::<TokenReply>(sys, &token, ADMIN, ()), TokenReply::Done);
+    let mint = TokenAction::Mint { to: account(OWNER), amount: BUDGET };
+    assert_eq!(call::<TokenReply>(sys, &token, ADMIN, mint), TokenReply::Done);
+
+    let escrow = Program::current(sys);
+    let init = EscrowInit { treasury: account(TREASURY), usdc_token: token.id().into() };
+    assert_eq!(call::<EscrowReply>(sys, &escrow, ADMIN, init), EscrowReply::Done);
+    let set_verifier = EscrowAction::SetVerifier { verifier: account(VERIFIER) };
+    assert_eq!(call::<EscrowReply>(sys, &escrow, ADMIN, set_verifier), EscrowReply::Done);
+
+    (token, escrow)
+}
+
+/// Creates a project over `BUDGET` with a 10% late penalty and has `OWNER` fund it.
+pub fn fund_project(
+    sys: &System,
+    token: &Program<'_>,
+    escrow: &Program<'_>,
+    milestones: Vec<MilestoneSpec>,
+    stream_blocks: u32,
+) -> ProjectId {
+    let approve = TokenAction::Approve { spender: escrow.id().into(), amount: BUDGET };
+    assert_eq!(call::<TokenReply>(sys, token, OWNER, approve), TokenReply::Done);
+
+    let create = EscrowAction::CreateProject {
+        budget: BUDGET,
+        milestones,
+        split: PaymentSplit { upfront_bps: 2000, progress_bps: 4000, final_bps: 4000 },
+        late_penalty_bps: 1000,
+        stream_blocks,
+    };
+    let EscrowReply::ProjectCreated { project_id } = call(sys, escrow, OWNER, create) else {
+        panic!("the project was not created");
+    };
+    assert_eq!(call::<EscrowReply>(sys, escrow, OWNER, EscrowAction::FundProject { project_id }), EscrowReply::Done);
+    project_id
+}
+
+/// Selects `DEV` as the whole team, which starts the milestone deadlines.
+pub fn select_developer(sys: &System, escrow: &Program<'_>, project_id: ProjectId) {
+    let payees = vec![Payee { account: account(DEV), share_bps: 10000 }];
+    let select = EscrowAction::SelectDeveloper { project_id, payees };
+    assert_eq!(call::<EscrowReply>(sys, escrow, OWNER, select), EscrowReply::Done);
+}
+
+pub fn apply_progress(sys: &System, escrow: &Program<'_>, project_id: ProjectId, milestone_idx: u8, percent: u16) {
+    let apply = EscrowAction::ApplyProgress { project_id, milestone_idx, percent };
+    assert_eq!(call::<EscrowReply>(sys, escrow, VERIFIER, apply), EscrowReply::Done);
+}
+
+pub fn project(sys: &System, escrow: &Program<'_>, project_id: ProjectId) -> Project {
+    match call(sys, escrow, ADMIN, EscrowAction::GetProject { project_id }) {
+        EscrowReply::Project(project) => *project,
+        reply => panic!("unexpected reply {reply:?}"),
+    }
+}
+
+pub fn owed(sys: &System, escrow: &Program<'_>, user: u64) -> u128 {
+    match call(sys, escrow, ADMIN, EscrowAction::GetOwed { account: account(user) }) {
+        EscrowReply::Owed(amount) => amount,
+        reply => panic!("unexpected reply {reply:?}"),
+    }
+}
+
+pub fn balance(sys: &System, token: &Program<'_>, user: u64) -> u128 {
+    match call(sys, token, ADMIN, TokenAction::BalanceOf { account: account(user) }) {
+        TokenReply::Balance(amount) => amount,
+        reply => panic!("unexpected reply {reply:?}"),
+    }
+}
@@ -0,0 +1,45 @@
+//! Arbitrated disputes, run against the escrow's WASM.
+
+mod common;
+
+use common::*;
+use gtest::System;
+use hvs_io::{EscrowAction, EscrowReply, MilestoneSpec, ProjectStatus};
+
+#[test]
+fn ruling_splits_streams_still_vesting_and_closes_them() {
+    let sys = System::new();
+    let (token, escrow) = deploy(&sys);
+    let milestones = vec![MilestoneSpec { weight_bps: 10000, deadline_blocks: 0 }];
+    let project_id = fund_project(&sys, &token, &escrow, milestones, 100);
+    let set_arbiter = EscrowAction::SetArbiter { project_id, arbiter: account(ARBITER) };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, OWNER, set_arbiter), EscrowReply::Done);
+    select_developer(&sys, &escrow, project_id);
+
+    // Half the milestone streams 19_000 over 100 blocks; the developer claims what vested so far.
+    apply_progress(&sys, &escrow, project_id, 1, 5000);
+    run_blocks(&sys, 9);
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, DEV, EscrowAction::Claim { project_id }), EscrowReply::Done);
+    let claimed = owed(&sys, &escrow, DEV) - UPFRONT_POOL;
+    assert!(claimed > 0 && claimed < 19_000, "claimed {claimed}");
+
+    let raise = EscrowAction::RaiseDispute { project_id };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, OWNER, raise), EscrowReply::Done);
+    let resolve = EscrowAction::ResolveDispute { project_id, developer_bps: 5000 };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, ARBITER, resolve), EscrowReply::Done);
+
+    // The unclaimed rest of the stream is split along with the final pool.
+    let held = BUDGET - BUDGET / 20 - UPFRONT_POOL - claimed;
+    let developer_amount = held / 2;
+    let state = project(&sys, &escrow, project_id);
+    assert_eq!(state.status, ProjectStatus::Resolved);
+    assert!(state.streams.is_empty());
+    assert_eq!(state.dispute, None);
+    assert_eq!(state.awarded, developer_amount);
+    assert_eq!(owed(&sys, &escrow, DEV), UPFRONT_POOL + claimed + developer_amount);
+    assert_eq!(balance(&sys, &token, OWNER), held - developer_amount);
+
+    // Nothing is left to claim from the closed streams.
+    let get_claimable = EscrowAction::GetClaimable { project_id };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, ADMIN, get_claimable), EscrowReply::Claimable(0));
+}
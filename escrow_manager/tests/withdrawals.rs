@@ -0,0 +1,37 @@
+//! Withdrawing credited payouts, run against the escrow's WASM.
+
+mod common;
+
+use common::*;
+use gtest::System;
+use hvs_io::{Error, EscrowAction, EscrowReply, MilestoneSpec};
+
+#[test]
+fn developer_withdraws_credited_payouts_in_parts() {
+    let sys = System::new();
+    let (token, escrow) = deploy(&sys);
+    let milestones = vec![MilestoneSpec { weight_bps: 10000, deadline_blocks: 0 }];
+    let project_id = fund_project(&sys, &token, &escrow, milestones, 0);
+    select_developer(&sys, &escrow, project_id);
+
+    // Selection credits the upfront pool without transferring anything.
+    assert_eq!(owed(&sys, &escrow, DEV), UPFRONT_POOL);
+    assert_eq!(balance(&sys, &token, DEV), 0);
+
+    let too_much = EscrowAction::Withdraw { amount: UPFRONT_POOL + 1 };
+    let refused = EscrowReply::Failed(Error::InsufficientBalance);
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, DEV, too_much), refused);
+    let nothing_owed = EscrowAction::Withdraw { amount: 1 };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, OWNER, nothing_owed), refused);
+    assert_eq!(owed(&sys, &escrow, DEV), UPFRONT_POOL);
+
+    let first = EscrowAction::Withdraw { amount: 12_000 };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, DEV, first), EscrowReply::Done);
+    assert_eq!(owed(&sys, &escrow, DEV), UPFRONT_POOL - 12_000);
+    assert_eq!(balance(&sys, &token, DEV), 12_000);
+
+    let rest = EscrowAction::Withdraw { amount: UPFRONT_POOL - 12_000 };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, DEV, rest), EscrowReply::Done);
+    assert_eq!(owed(&sys, &escrow, DEV), 0);
+    assert_eq!(balance(&sys, &token, DEV), UPFRONT_POOL);
+}
@@ -0,0 +1,196 @@
+use alloc::{string::String, vec::Vec};
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+use crate::{AccountId, Error, ProjectId};
+
+/// Init payload of `verification_bridge`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct BridgeInit {
+    pub escrow_manager: AccountId,
+}
+
+/// A registered relayer; only enabled relayers may submit attestations.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Relayer {
+    pub account: AccountId,
+    pub label: String,
+    pub added_at: u32,
+    pub enabled: bool,
+    /// Key whose signatures stand in for the relayer's own messages, if registered.
+    pub key: Option<RelayerKey>,
+}
+
+/// A public key a relayer signs attestations with off-chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum RelayerKey {
+    #[codec(index = 0)]
+    Sr25519([u8; 32]),
+    #[codec(index = 1)]
+    Ed25519([u8; 32]),
+}
+
+impl RelayerKey {
+    /// Checks `signature` over `message` against this key.
+    ///
+    /// Sr25519 signatures use the `substrate` signing context, as produced by Polkadot.js keyrings.
+    pub fn verify(&self, message: &[u8], signature: &[u8; 64]) -> bool {
+        match self {
+            RelayerKey::Sr25519(public) => {
+                let (Ok(public), Ok(signature)) =
+                    (schnorrkel::PublicKey::from_bytes(public), schnorrkel::Signature::from_bytes(signature))
+                else {
+                    return false;
+                };
+                public.verify_simple(b"substrate", message, &signature).is_ok()
+            }
+            RelayerKey::Ed25519(public) => {
+                let Ok(public) = ed25519_dalek::VerifyingKey::from_bytes(public) else {
+                    return false;
+                };
+                public.verify_strict(message, &ed25519_dalek::Signature::from_bytes(signature)).is_ok()
+            }
+        }
+    }
+}
+
+/// An attestation signed off-chain by a relayer, valid until block `expires_at`.
+///
+/// `nonce` shares the relayer's sequence with [`BridgeAction::SubmitAttestation`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Attestation {
+    pub project_id: ProjectId,
+    pub milestone_idx: u8,
+    pub percent: u16,
+    pub tests_passed: bool,
+    pub nonce: u64,
+    pub expires_at: u32,
+}
+
+impl Attestation {
+    /// The bytes a relayer signs: the bridge's program id followed by the encoded attestation, so a
+    /// signature cannot be replayed against another bridge.
+    pub fn signing_message(&self, bridge: AccountId) -> Vec<u8> {
+        (bridge, self).encode()
+    }
+}
+
+/// One relayer's report in a pending tally.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Report {
+    pub relayer: AccountId,
+    pub percent: u16,
+}
+
+/// Reports collected for a milestone until enough relayers agree or the tally expires; in median
+/// mode, the round of reports aggregated when its window ends at `expires_at`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Tally {
+    pub expires_at: u32,
+    pub reports: Vec<Report>,
+}
+
+/// Actions handled by `verification_bridge`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum BridgeAction {
+    /// An enabled relayer reports progress; `percent` is in basis points. With a quorum above 1 the
+    /// report is tallied and progress is forwarded once enough relayers agree.
+    ///
+    /// `nonce` must exceed every nonce already accepted from the relayer, signed or not, and the
+    /// report is refused after block `expires_at`.
+    #[codec(index = 2)]
+    SubmitAttestation {
+        project_id: ProjectId,
+        milestone_idx: u8,
+        percent: u16,
+        tests_passed: bool,
+        nonce: u64,
+        expires_at: u32,
+    },
+    /// Replies [`BridgeReply::LastPercent`].
+    #[codec(index = 3)]
+    GetLastPercent { project_id: ProjectId, milestone_idx: u8 },
+    #[codec(index = 4)]
+    SetEscrow { escrow_manager: AccountId },
+    /// Requires `threshold` distinct relayers to report within `tolerance_bps` of each other before
+    /// progress is forwarded; tallies lapse `timeout_blocks` after their first report (admin only).
+    #[codec(index = 5)]
+    SetQuorum { threshold: u8, tolerance_bps: u16, timeout_blocks: u32 },
+    /// Replies [`BridgeReply::Tally`].
+    #[codec(index = 6)]
+    GetTally { project_id: ProjectId, milestone_idx: u8 },
+    /// Scheduled by the bridge to itself when a tally times out; rejected from anyone else.
+    #[codec(index = 7)]
+    ExpireTally { project_id: ProjectId, milestone_idx: u8 },
+    /// Aggregates reports in rounds of `window_blocks` instead: the round's median is forwarded after
+    /// discarding reports more than `max_deviation_bps` from it, provided at least the quorum remain.
+    /// A window of 0 returns to quorum mode; switching modes drops pending tallies (admin only).
+    #[codec(index = 8)]
+    SetMedianRounds { window_blocks: u32, max_deviation_bps: u16 },
+    /// Scheduled by the bridge to itself when a round's window ends; rejected from anyone else. The next
+    /// report on the milestone closes an ended round too.
+    #[codec(index = 9)]
+    CloseRound { project_id: ProjectId, milestone_idx: u8 },
+    /// Registers an enabled relayer; `label` is at most 64 bytes (admin only).
+    #[codec(index = 10)]
+    AddRelayer { relayer: AccountId, label: String },
+    /// Unregisters a relayer and drops its pending reports (admin only).
+    #[codec(index = 11)]
+    RemoveRelayer { relayer: AccountId },
+    /// Pauses or resumes a relayer; disabling drops its pending reports (admin only).
+    #[codec(index = 12)]
+    SetRelayerEnabled { relayer: AccountId, enabled: bool },
+    /// Replies [`BridgeReply::Relayers`], only the enabled ones when `active_only` is set.
+    #[codec(index = 13)]
+    GetRelayers { active_only: bool },
+    /// Registers or clears the key a relayer signs attestations with (admin only).
+    #[codec(index = 14)]
+    SetRelayerKey { relayer: AccountId, key: Option<RelayerKey> },
+    /// Anyone submits an attestation signed with an enabled relayer's key; once the signature is
+    /// verified it is handled as if the relayer had sent [`BridgeAction::SubmitAttestation`].
+    #[codec(index = 15)]
+    SubmitSignedAttestation { relayer: AccountId, attestation: Attestation, signature: [u8; 64] },
+}
+
+/// Replies sent by `verification_bridge`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum BridgeReply {
+    #[codec(index = 0)]
+    Failed(Error),
+    #[codec(index = 1)]
+    Done,
+    #[codec(index = 2)]
+    LastPercent(u16),
+    #[codec(index = 3)]
+    Tally(Option<Tally>),
+    #[codec(index = 4)]
+    Relayers(Vec<Relayer>),
+}
+
+/// Events emitted by `verification_bridge` to the zero address.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum BridgeEvent {
+    #[codec(index = 2)]
+    AttestationAccepted { relayer: AccountId, project_id: ProjectId, milestone_idx: u8, percent: u16 },
+    #[codec(index = 3)]
+    AttestationRejected { relayer: AccountId, project_id: ProjectId, milestone_idx: u8, percent: u16 },
+    /// The report was tallied; `votes` relayers so far agree with it.
+    #[codec(index = 4)]
+    AttestationPending { relayer: AccountId, project_id: ProjectId, milestone_idx: u8, percent: u16, votes: u32 },
+    #[codec(index = 5)]
+    TallyExpired { project_id: ProjectId, milestone_idx: u8 },
+    /// The escrow applied a round's median; `discarded` reports were outliers.
+    #[codec(index = 6)]
+    RoundAccepted { project_id: ProjectId, milestone_idx: u8, percent: u16, counted: u32, discarded: u32 },
+    #[codec(index = 7)]
+    RoundRejected { project_id: ProjectId, milestone_idx: u8, percent: u16 },
+    #[codec(index = 8)]
+    RelayerAdded { relayer: AccountId, label: String },
+    #[codec(index = 9)]
+    RelayerRemoved { relayer: AccountId },
+    #[codec(index = 10)]
+    RelayerEnabled { relayer: AccountId, enabled: bool },
+    #[codec(index = 11)]
+    RelayerKeySet { relayer: AccountId, key: Option<RelayerKey> },
+}
@@ -0,0 +1,66 @@
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Why an action failed, shared by all three programs.
+///
+/// Failure replies encode as `[0, code]`, and `PolicyViolation` appends the
+/// reason: `[0, 7, reason]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum Error {
+    /// The sender is not allowed to perform the action.
+    #[codec(index = 1)]
+    Unauthorized,
+    /// The payload is malformed or carries an invalid value.
+    #[codec(index = 2)]
+    InvalidPayload,
+    /// The account does not hold enough tokens.
+    #[codec(index = 3)]
+    InsufficientBalance,
+    /// The spender's allowance does not cover the amount.
+    #[codec(index = 4)]
+    InsufficientAllowance,
+    /// A bounded collection or slot range is full.
+    #[codec(index = 5)]
+    CapacityExceeded,
+    /// An arithmetic operation overflowed.
+    #[codec(index = 6)]
+    Overflow,
+    /// An attestation or update broke one of the configured policies.
+    #[codec(index = 7)]
+    PolicyViolation { reason: PolicyReason },
+    /// The action is not allowed in the current state (e.g. funding twice).
+    #[codec(index = 8)]
+    InvalidState,
+    /// The referenced milestone, slot or record does not exist.
+    #[codec(index = 9)]
+    NotFound,
+    /// The action byte is not recognised.
+    #[codec(index = 10)]
+    UnknownAction,
+    /// A call to another program failed or was rejected.
+    #[codec(index = 11)]
+    ExternalCallFailed,
+    /// Payouts are frozen while the project has an open dispute.
+    #[codec(index = 12)]
+    DisputeOpen,
+    /// The attestation's nonce does not exceed the last one accepted from its relayer.
+    #[codec(index = 13)]
+    NonceReused,
+    /// The attestation arrived after its expiry block.
+    #[codec(index = 14)]
+    AttestationExpired,
+}
+
+/// Which policy an attestation or progress update broke.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum PolicyReason {
+    /// The new percentage does not exceed the last accepted one.
+    #[codec(index = 1)]
+    NotMonotonic,
+    /// The increase is smaller than the configured minimum step.
+    #[codec(index = 2)]
+    BelowMinStep,
+    /// Tests are required but were reported as failing.
+    #[codec(index = 3)]
+    TestsNotPassed,
+}
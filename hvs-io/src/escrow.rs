@@ -0,0 +1,315 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+use crate::{AccountId, Error};
+
+/// Identifier the escrow assigns to each project, in creation order.
+pub type ProjectId = u64;
+
+/// Init payload of `escrow_manager`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct EscrowInit {
+    pub treasury: AccountId,
+    pub usdc_token: AccountId,
+}
+
+/// Lifecycle of a project.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum ProjectStatus {
+    /// Created, waiting for the owner to fund it.
+    Created,
+    /// The budget is locked in the escrow; no developer yet.
+    Funded,
+    /// A developer is selected and milestones are being attested.
+    InProgress,
+    /// The final payment has been released.
+    Completed,
+    /// Ended early; the unreleased remainder was refunded to the owner.
+    Cancelled,
+    /// Closed by an arbiter's ruling on a dispute.
+    Resolved,
+    /// The budget is being pulled from the owner; the project becomes `Funded` once it arrives.
+    Funding,
+}
+
+/// An open disagreement between the owner and the developer; payouts are frozen until it closes.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Dispute {
+    pub raised_by: AccountId,
+    pub raised_at: u32,
+    /// Last block at which the arbiter can rule; afterwards anyone can expire the dispute.
+    pub deadline: u32,
+}
+
+/// Terms of a milestone supplied at project creation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct MilestoneSpec {
+    /// Share of the progress pool in basis points; the weights of a project sum to 10000.
+    pub weight_bps: u16,
+    /// Blocks after developer selection by which the milestone is due; 0 for no deadline.
+    pub deadline_blocks: u32,
+}
+
+/// One deliverable of a project; milestones are addressed by their 1-based position.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Milestone {
+    pub weight_bps: u16,
+    pub deadline_blocks: u32,
+    /// Block the milestone is due at, once a developer is selected; pushed back by the time a dispute
+    /// froze progress reports.
+    pub due_at: Option<u32>,
+    /// Completion in basis points (0-10000).
+    pub percent: u16,
+    /// Completion when the deadline passed, if it passed unfinished. Progress beyond
+    /// it is paid minus the project's late penalty.
+    pub overdue_from: Option<u16>,
+}
+
+/// How a project's budget net of the platform fee is paid out, in basis points summing to 10000.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct PaymentSplit {
+    /// Released to the developer when they are selected.
+    pub upfront_bps: u16,
+    /// Released as milestones progress.
+    pub progress_bps: u16,
+    /// Released on final approval.
+    pub final_bps: u16,
+}
+
+/// A member of the developer team and their share of every release, in basis points.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Payee {
+    pub account: AccountId,
+    pub share_bps: u16,
+}
+
+/// New payee shares waiting for the owner and every current payee to approve them.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ShareProposal {
+    pub payees: Vec<Payee>,
+    pub approvals: Vec<AccountId>,
+    pub proposed_by: AccountId,
+    /// Block from which the proposal can no longer be approved and may be replaced.
+    pub expires_at: u32,
+}
+
+/// A progress payout vesting linearly to the developer between two blocks.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Stream {
+    pub amount: u128,
+    pub start: u32,
+    pub end: u32,
+    pub claimed: u128,
+}
+
+/// A single client engagement held by the escrow.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct Project {
+    pub owner: AccountId,
+    /// The developer team, empty until it is selected; shares sum to 10000 bps.
+    pub payees: Vec<Payee>,
+    pub budget: u128,
+    /// Platform fee routed to the treasury on funding.
+    pub fee: u128,
+    pub status: ProjectStatus,
+    pub split: PaymentSplit,
+    /// Cut of progress completed after a milestone's deadline, in basis points.
+    pub late_penalty_bps: u16,
+    /// Blocks each progress payout vests over; 0 pays progress in a lump sum.
+    pub stream_blocks: u32,
+    pub upfront_pool: u128,
+    pub progress_pool: u128,
+    pub final_pool: u128,
+    pub released_upfront: u128,
+    pub released_progress: u128,
+    pub released_final: u128,
+    /// Paid to the developer when the project was closed early by cancellation or a ruling.
+    pub awarded: u128,
+    /// Returned to the owner when the project was closed early by cancellation or a ruling.
+    pub refunded: u128,
+    pub milestones: Vec<Milestone>,
+    /// Parties that agreed to cancel an in-progress project; it is cancelled once the owner and every
+    /// payee have.
+    pub cancel_approvals: Vec<AccountId>,
+    /// Rules on disputes; the platform admin rules when none is set.
+    pub arbiter: Option<AccountId>,
+    pub dispute: Option<Dispute>,
+    /// Block after which the final pool is released without the owner, once every milestone is complete.
+    pub acceptance_deadline: Option<u32>,
+    /// Progress payouts still vesting or not yet claimed.
+    pub streams: Vec<Stream>,
+    pub share_proposal: Option<ShareProposal>,
+}
+
+/// Actions handled by `escrow_manager`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum EscrowAction {
+    /// Registers a project owned by the sender. Replies [`EscrowReply::ProjectCreated`].
+    #[codec(index = 1)]
+    CreateProject {
+        budget: u128,
+        milestones: Vec<MilestoneSpec>,
+        split: PaymentSplit,
+        late_penalty_bps: u16,
+        stream_blocks: u32,
+    },
+    /// Pulls the budget from the owner, who must have approved the escrow on the token.
+    #[codec(index = 2)]
+    FundProject { project_id: ProjectId },
+    /// Selects the developer team, whose shares must sum to 10000 bps, and credits the upfront pool, if any.
+    #[codec(index = 3)]
+    SelectDeveloper { project_id: ProjectId, payees: Vec<Payee> },
+    /// Sent by the authorized verifier; `percent` is in basis points.
+    #[codec(index = 4)]
+    ApplyProgress { project_id: ProjectId, milestone_idx: u8, percent: u16 },
+    /// Sent by the owner, or by anyone once the acceptance window has passed. Credits the final pool to
+    /// the team and progress forfeited to late penalties to the owner.
+    #[codec(index = 5)]
+    MarkFinalApproved { project_id: ProjectId },
+    /// Platform admin only.
+    #[codec(index = 6)]
+    SetVerifier { verifier: AccountId },
+    /// Platform admin only.
+    #[codec(index = 7)]
+    SetTreasury { treasury: AccountId },
+    /// Platform admin only.
+    #[codec(index = 8)]
+    SetFeeBps { fee_bps: u16 },
+    /// Replies [`EscrowReply::Project`].
+    #[codec(index = 9)]
+    GetProject { project_id: ProjectId },
+    /// The owner cancels before a developer is selected. Afterwards the owner and every payee
+    /// must send it, unless the platform admin arbitrates and cancels on its own.
+    #[codec(index = 10)]
+    CancelProject { project_id: ProjectId },
+    /// The owner or the developer freezes payouts until the dispute is resolved, withdrawn or expires.
+    #[codec(index = 11)]
+    RaiseDispute { project_id: ProjectId },
+    /// The party that raised the dispute drops it.
+    #[codec(index = 12)]
+    WithdrawDispute { project_id: ProjectId },
+    /// Anyone closes a dispute the arbiter did not rule on before its deadline.
+    #[codec(index = 13)]
+    ExpireDispute { project_id: ProjectId },
+    /// The owner sets the arbiter until the developer is selected; the platform admin at any time.
+    /// The arbiter can be neither the owner nor a payee.
+    #[codec(index = 14)]
+    SetArbiter { project_id: ProjectId, arbiter: AccountId },
+    /// The arbiter awards `developer_bps` of the funds still held, streams not yet claimed included, to
+    /// the developer and refunds the rest to the owner, closing the project and its streams.
+    #[codec(index = 15)]
+    ResolveDispute { project_id: ProjectId, developer_bps: u16 },
+    /// Scheduled by the escrow to itself when a milestone falls due; rejected from anyone else.
+    #[codec(index = 16)]
+    MilestoneDeadline { project_id: ProjectId, milestone_idx: u8 },
+    /// Platform admin only; 0 disables auto-approval.
+    #[codec(index = 17)]
+    SetAcceptanceWindow { blocks: u32 },
+    /// The owner turns down the delivery within the acceptance window, stopping auto-approval.
+    #[codec(index = 18)]
+    RejectDelivery { project_id: ProjectId },
+    /// Scheduled by the escrow to itself when the acceptance window closes; rejected from anyone else.
+    #[codec(index = 19)]
+    AutoApprove { project_id: ProjectId },
+    /// A payee credits what has vested of a streaming project's payouts to the team, by current shares.
+    #[codec(index = 20)]
+    Claim { project_id: ProjectId },
+    /// Replies [`EscrowReply::Claimable`].
+    #[codec(index = 21)]
+    GetClaimable { project_id: ProjectId },
+    /// Pays `amount` of what the sender is owed out of the escrow.
+    #[codec(index = 22)]
+    Withdraw { amount: u128 },
+    /// Replies [`EscrowReply::Owed`].
+    #[codec(index = 23)]
+    GetOwed { account: AccountId },
+    /// The owner or a payee proposes new shares while none are pending or the pending proposal has expired;
+    /// it counts as their approval.
+    #[codec(index = 24)]
+    ProposeShares { project_id: ProjectId, payees: Vec<Payee> },
+    /// The owner or a current payee approves the pending proposal; the last approval applies it.
+    #[codec(index = 25)]
+    ApproveShares { project_id: ProjectId },
+    /// The proposer drops their pending proposal.
+    #[codec(index = 26)]
+    WithdrawShares { project_id: ProjectId },
+}
+
+/// Replies sent by `escrow_manager`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum EscrowReply {
+    #[codec(index = 0)]
+    Failed(Error),
+    #[codec(index = 1)]
+    Done,
+    #[codec(index = 2)]
+    ProjectCreated { project_id: ProjectId },
+    #[codec(index = 3)]
+    Project(Box<Project>),
+    #[codec(index = 4)]
+    Claimable(u128),
+    #[codec(index = 5)]
+    Owed(u128),
+}
+
+/// Events emitted by `escrow_manager` to the zero address.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum EscrowEvent {
+    #[codec(index = 1)]
+    ProjectCreated { project_id: ProjectId, owner: AccountId, budget: u128 },
+    #[codec(index = 2)]
+    ProjectFunded {
+        project_id: ProjectId,
+        budget: u128,
+        fee: u128,
+        upfront_pool: u128,
+        progress_pool: u128,
+        final_pool: u128,
+    },
+    #[codec(index = 3)]
+    DeveloperSelected { project_id: ProjectId, payees: Vec<Payee> },
+    #[codec(index = 4)]
+    ProgressApplied { project_id: ProjectId, milestone_idx: u8, percent: u16, payout: u128 },
+    /// Credited to what `to` is owed; paid out with `Withdraw`.
+    #[codec(index = 5)]
+    PaymentReleased { project_id: ProjectId, to: AccountId, amount: u128 },
+    #[codec(index = 6)]
+    FinalApproved { project_id: ProjectId, amount: u128 },
+    /// One party agreed to cancel and waits for the others.
+    #[codec(index = 7)]
+    CancellationRequested { project_id: ProjectId, by: AccountId },
+    #[codec(index = 8)]
+    ProjectCancelled { project_id: ProjectId, refund: u128, developer_payout: u128 },
+    #[codec(index = 9)]
+    DisputeRaised { project_id: ProjectId, by: AccountId, deadline: u32 },
+    #[codec(index = 10)]
+    ArbiterSet { project_id: ProjectId, arbiter: AccountId },
+    #[codec(index = 11)]
+    DisputeResolved { project_id: ProjectId, developer_amount: u128, owner_amount: u128 },
+    #[codec(index = 12)]
+    DisputeWithdrawn { project_id: ProjectId },
+    #[codec(index = 13)]
+    DisputeExpired { project_id: ProjectId },
+    /// The milestone's deadline passed before it reached 10000 bps.
+    #[codec(index = 14)]
+    MilestoneOverdue { project_id: ProjectId, milestone_idx: u8, percent: u16 },
+    /// Every milestone is complete; the final pool is released after `deadline` unless the owner objects.
+    #[codec(index = 15)]
+    AwaitingAcceptance { project_id: ProjectId, deadline: u32 },
+    #[codec(index = 16)]
+    DeliveryRejected { project_id: ProjectId },
+    #[codec(index = 17)]
+    StreamStarted { project_id: ProjectId, amount: u128, start: u32, end: u32 },
+    #[codec(index = 18)]
+    Withdrawn { to: AccountId, amount: u128 },
+    #[codec(index = 19)]
+    SharesProposed { project_id: ProjectId, by: AccountId, payees: Vec<Payee> },
+    #[codec(index = 20)]
+    SharesApproved { project_id: ProjectId, by: AccountId },
+    #[codec(index = 21)]
+    SharesChanged { project_id: ProjectId, payees: Vec<Payee> },
+    #[codec(index = 22)]
+    SharesWithdrawn { project_id: ProjectId },
+}
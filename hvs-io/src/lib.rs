@@ -0,0 +1,49 @@
+//! Wire format shared by the USDC token, escrow manager and verification bridge.
+//!
+//! Every action, reply and event is a SCALE-encoded enum whose variant indices
+//! are pinned with `#[codec(index = N)]`, so the first byte of a payload is the
+//! action byte the programs have always dispatched on and the fields follow in
+//! their original little-endian layout.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod bridge;
+pub mod error;
+pub mod escrow;
+pub mod token;
+
+pub use bridge::{Attestation, BridgeAction, BridgeEvent, BridgeInit, BridgeReply, Relayer, RelayerKey, Report, Tally};
+pub use error::{Error, PolicyReason};
+pub use escrow::{
+    Dispute, EscrowAction, EscrowEvent, EscrowInit, EscrowReply, Milestone, MilestoneSpec, Payee,
+    PaymentSplit, Project, ProjectId, ProjectStatus, ShareProposal, Stream,
+};
+pub use token::{TokenAction, TokenEvent, TokenReply};
+
+pub use parity_scale_codec::{Decode, DecodeAll, Encode};
+pub use scale_info::TypeInfo;
+
+use scale_info::TypeDef;
+
+/// A 32-byte account or program id, as stored by every program.
+pub type AccountId = [u8; 32];
+
+/// Decodes an action payload, rejecting trailing bytes.
+///
+/// Fails with [`Error::UnknownAction`] when the first byte names no action of `T` and with
+/// [`Error::InvalidPayload`] when the action's fields do not decode.
+pub fn decode_action<T: DecodeAll + TypeInfo>(payload: &[u8]) -> Result<T, Error> {
+    T::decode_all(&mut &payload[..]).map_err(|_| {
+        let known = match (payload.first(), T::type_info().type_def) {
+            (Some(&byte), TypeDef::Variant(def)) => def.variants.iter().any(|variant| variant.index == byte),
+            _ => true,
+        };
+        if known {
+            Error::InvalidPayload
+        } else {
+            Error::UnknownAction
+        }
+    })
+}
@@ -0,0 +1,71 @@
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+use crate::{AccountId, Error};
+
+/// Actions handled by `usdc_token`. The zero address is rejected as a recipient or spender.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum TokenAction {
+    /// Mints new tokens (admin only).
+    #[codec(index = 1)]
+    Mint { to: AccountId, amount: u128 },
+    /// Transfers tokens from the sender.
+    #[codec(index = 2)]
+    Transfer { to: AccountId, amount: u128 },
+    /// Sets the spender's allowance on the sender's account; 0 revokes it.
+    #[codec(index = 3)]
+    Approve { spender: AccountId, amount: u128 },
+    /// Transfers tokens from `from`, spending the sender's allowance unless `from` is the sender.
+    #[codec(index = 4)]
+    TransferFrom { from: AccountId, to: AccountId, amount: u128 },
+    /// Queries an account's balance. Replies [`TokenReply::Balance`].
+    #[codec(index = 5)]
+    BalanceOf { account: AccountId },
+    /// Queries a spender's allowance. Replies [`TokenReply::Allowance`].
+    #[codec(index = 6)]
+    Allowance { owner: AccountId, spender: AccountId },
+    /// Adds to the spender's allowance on the sender's account.
+    #[codec(index = 7)]
+    IncreaseAllowance { spender: AccountId, amount: u128 },
+    /// Subtracts from the spender's allowance, failing if it is smaller.
+    #[codec(index = 8)]
+    DecreaseAllowance { spender: AccountId, amount: u128 },
+    /// Burns tokens held by the sender.
+    #[codec(index = 9)]
+    Burn { amount: u128 },
+    /// Burns tokens from `from`, spending the sender's allowance unless `from` is the sender.
+    #[codec(index = 10)]
+    BurnFrom { from: AccountId, amount: u128 },
+    /// Queries the total supply. Replies [`TokenReply::TotalSupply`].
+    #[codec(index = 11)]
+    TotalSupply,
+}
+
+/// Replies sent by `usdc_token`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum TokenReply {
+    #[codec(index = 0)]
+    Failed(Error),
+    #[codec(index = 1)]
+    Done,
+    #[codec(index = 2)]
+    Balance(u128),
+    #[codec(index = 3)]
+    Allowance(u128),
+    #[codec(index = 4)]
+    TotalSupply(u128),
+}
+
+/// Events emitted by `usdc_token` to the zero address.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum TokenEvent {
+    #[codec(index = 1)]
+    Transfer { from: AccountId, to: AccountId, amount: u128 },
+    /// Carries the allowance after the change.
+    #[codec(index = 2)]
+    Approval { owner: AccountId, spender: AccountId, allowance: u128 },
+    #[codec(index = 3)]
+    Mint { to: AccountId, amount: u128 },
+    #[codec(index = 4)]
+    Burn { from: AccountId, amount: u128 },
+}
@@ -0,0 +1,109 @@
+//! Checks relayer signatures the way `verification_bridge` does for signed attestations.
+
+use ed25519_dalek::{Signer, SigningKey};
+use hvs_io::{Attestation, RelayerKey};
+use rand_core::{CryptoRng, RngCore};
+use schnorrkel::{context::attach_rng, signing_context, ExpansionMode, Keypair, MiniSecretKey};
+
+const BRIDGE: [u8; 32] = [9; 32];
+const OTHER_BRIDGE: [u8; 32] = [8; 32];
+
+/// Fixed nonce source for sr25519 signing; the crate is built without an OS RNG.
+struct TestRng(u8);
+
+impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            self.0 = self.0.wrapping_add(1);
+            *byte = self.0;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for TestRng {}
+
+fn attestation() -> Attestation {
+    Attestation { project_id: 1, milestone_idx: 1, percent: 5000, tests_passed: true, nonce: 1, expires_at: 100 }
+}
+
+fn sr25519_keypair(seed: u8) -> Keypair {
+    MiniSecretKey::from_bytes(&[seed; 32]).unwrap().expand_to_keypair(ExpansionMode::Ed25519)
+}
+
+fn sr25519_sign(keypair: &Keypair, message: &[u8]) -> [u8; 64] {
+    let transcript = attach_rng(signing_context(b"substrate").bytes(message), TestRng(0));
+    keypair.sign(transcript).to_bytes()
+}
+
+#[test]
+fn sr25519_signatures_are_checked() {
+    let keypair = sr25519_keypair(1);
+    let key = RelayerKey::Sr25519(keypair.public.to_bytes());
+    let message = attestation().signing_message(BRIDGE);
+    let signature = sr25519_sign(&keypair, &message);
+
+    assert!(key.verify(&message, &signature));
+
+    // Another bridge, another attestation, another signer or a corrupted signature are all rejected.
+    assert!(!key.verify(&attestation().signing_message(OTHER_BRIDGE), &signature));
+    let tampered = Attestation { percent: 10000, ..attestation() };
+    assert!(!key.verify(&tampered.signing_message(BRIDGE), &signature));
+    assert!(!key.verify(&message, &sr25519_sign(&sr25519_keypair(2), &message)));
+    let mut corrupted = signature;
+    corrupted[0] ^= 1;
+    assert!(!key.verify(&message, &corrupted));
+}
+
+#[test]
+fn ed25519_signatures_are_checked() {
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let key = RelayerKey::Ed25519(signing_key.verifying_key().to_bytes());
+    let message = attestation().signing_message(BRIDGE);
+    let signature = signing_key.sign(&message).to_bytes();
+
+    assert!(key.verify(&message, &signature));
+
+    assert!(!key.verify(&attestation().signing_message(OTHER_BRIDGE), &signature));
+    let tampered = Attestation { nonce: 2, ..attestation() };
+    assert!(!key.verify(&tampered.signing_message(BRIDGE), &signature));
+    assert!(!key.verify(&message, &SigningKey::from_bytes(&[2; 32]).sign(&message).to_bytes()));
+    let mut corrupted = signature;
+    corrupted[63] ^= 1;
+    assert!(!key.verify(&message, &corrupted));
+}
+
+#[test]
+fn a_signature_only_verifies_under_its_key_type() {
+    let message = attestation().signing_message(BRIDGE);
+    let keypair = sr25519_keypair(1);
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+
+    let sr25519_signature = sr25519_sign(&keypair, &message);
+    let ed25519_signature = signing_key.sign(&message).to_bytes();
+
+    assert!(!RelayerKey::Ed25519(keypair.public.to_bytes()).verify(&message, &sr25519_signature));
+    assert!(!RelayerKey::Sr25519(signing_key.verifying_key().to_bytes()).verify(&message, &ed25519_signature));
+}
+
+#[test]
+fn malformed_keys_are_rejected() {
+    let message = attestation().signing_message(BRIDGE);
+    let signature = sr25519_sign(&sr25519_keypair(1), &message);
+
+    // Not a valid compressed point in either encoding.
+    assert!(!RelayerKey::Sr25519([0xff; 32]).verify(&message, &signature));
+    assert!(!RelayerKey::Ed25519([0xff; 32]).verify(&message, &signature));
+}
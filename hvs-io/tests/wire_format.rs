@@ -0,0 +1,270 @@
+//! Pins the byte layout the programs and the JavaScript SDK agree on.
+
+use hvs_io::{
+    Attestation, BridgeAction, BridgeInit, BridgeReply, Decode, DecodeAll, Dispute, Encode, Error, EscrowAction,
+    EscrowInit, EscrowReply, Milestone, MilestoneSpec, Payee, PaymentSplit, PolicyReason, Project, ProjectStatus,
+    Relayer, RelayerKey, Report, ShareProposal, Stream, Tally, TokenAction, TokenReply,
+};
+
+const ALICE: [u8; 32] = [1; 32];
+const BOB: [u8; 32] = [2; 32];
+
+fn round_trip<T: Encode + Decode + PartialEq + core::fmt::Debug>(value: T) {
+    let bytes = value.encode();
+    assert_eq!(T::decode_all(&mut &bytes[..]).unwrap(), value);
+}
+
+fn action_byte<T: Encode>(value: &T) -> u8 {
+    value.encode()[0]
+}
+
+#[test]
+fn token_actions_keep_their_action_bytes() {
+    let actions = [
+        (TokenAction::Mint { to: ALICE, amount: 1 }, 1),
+        (TokenAction::Transfer { to: ALICE, amount: 1 }, 2),
+        (TokenAction::Approve { spender: ALICE, amount: 1 }, 3),
+        (TokenAction::TransferFrom { from: ALICE, to: BOB, amount: 1 }, 4),
+        (TokenAction::BalanceOf { account: ALICE }, 5),
+        (TokenAction::Allowance { owner: ALICE, spender: BOB }, 6),
+        (TokenAction::IncreaseAllowance { spender: ALICE, amount: 1 }, 7),
+        (TokenAction::DecreaseAllowance { spender: ALICE, amount: 1 }, 8),
+        (TokenAction::Burn { amount: 1 }, 9),
+        (TokenAction::BurnFrom { from: ALICE, amount: 1 }, 10),
+        (TokenAction::TotalSupply, 11),
+    ];
+    for (action, byte) in actions {
+        assert_eq!(action_byte(&action), byte, "{action:?}");
+        round_trip(action);
+    }
+
+    // [2, to(32), amount(16 LE)]
+    let mut expected = vec![2];
+    expected.extend_from_slice(&BOB);
+    expected.extend_from_slice(&500u128.to_le_bytes());
+    assert_eq!(TokenAction::Transfer { to: BOB, amount: 500 }.encode(), expected);
+}
+
+#[test]
+fn escrow_actions_keep_their_action_bytes() {
+    let split = PaymentSplit { upfront_bps: 2000, progress_bps: 4000, final_bps: 4000 };
+    let payees = vec![Payee { account: BOB, share_bps: 10000 }];
+    let actions = [
+        (
+            EscrowAction::CreateProject {
+                budget: 1,
+                milestones: vec![MilestoneSpec { weight_bps: 10000, deadline_blocks: 0 }],
+                split,
+                late_penalty_bps: 0,
+                stream_blocks: 0,
+            },
+            1,
+        ),
+        (EscrowAction::FundProject { project_id: 1 }, 2),
+        (EscrowAction::SelectDeveloper { project_id: 1, payees: payees.clone() }, 3),
+        (EscrowAction::ApplyProgress { project_id: 1, milestone_idx: 1, percent: 1 }, 4),
+        (EscrowAction::MarkFinalApproved { project_id: 1 }, 5),
+        (EscrowAction::SetVerifier { verifier: ALICE }, 6),
+        (EscrowAction::SetTreasury { treasury: ALICE }, 7),
+        (EscrowAction::SetFeeBps { fee_bps: 1 }, 8),
+        (EscrowAction::GetProject { project_id: 1 }, 9),
+        (EscrowAction::CancelProject { project_id: 1 }, 10),
+        (EscrowAction::RaiseDispute { project_id: 1 }, 11),
+        (EscrowAction::WithdrawDispute { project_id: 1 }, 12),
+        (EscrowAction::ExpireDispute { project_id: 1 }, 13),
+        (EscrowAction::SetArbiter { project_id: 1, arbiter: ALICE }, 14),
+        (EscrowAction::ResolveDispute { project_id: 1, developer_bps: 1 }, 15),
+        (EscrowAction::MilestoneDeadline { project_id: 1, milestone_idx: 1 }, 16),
+        (EscrowAction::SetAcceptanceWindow { blocks: 1 }, 17),
+        (EscrowAction::RejectDelivery { project_id: 1 }, 18),
+        (EscrowAction::AutoApprove { project_id: 1 }, 19),
+        (EscrowAction::Claim { project_id: 1 }, 20),
+        (EscrowAction::GetClaimable { project_id: 1 }, 21),
+        (EscrowAction::Withdraw { amount: 1 }, 22),
+        (EscrowAction::GetOwed { account: ALICE }, 23),
+        (EscrowAction::ProposeShares { project_id: 1, payees }, 24),
+        (EscrowAction::ApproveShares { project_id: 1 }, 25),
+        (EscrowAction::WithdrawShares { project_id: 1 }, 26),
+    ];
+    for (action, byte) in actions {
+        assert_eq!(action_byte(&action), byte, "{action:?}");
+        round_trip(action);
+    }
+
+    // [4, project_id(8 LE), milestone_idx(1), percent(2 LE)]
+    assert_eq!(
+        EscrowAction::ApplyProgress { project_id: 258, milestone_idx: 2, percent: 5000 }.encode(),
+        [4, 2, 1, 0, 0, 0, 0, 0, 0, 2, 0x88, 0x13]
+    );
+}
+
+#[test]
+fn bridge_actions_keep_their_action_bytes() {
+    let attestation =
+        Attestation { project_id: 1, milestone_idx: 1, percent: 1, tests_passed: true, nonce: 1, expires_at: 1 };
+    let actions = [
+        (
+            BridgeAction::SubmitAttestation {
+                project_id: 1,
+                milestone_idx: 1,
+                percent: 1,
+                tests_passed: true,
+                nonce: 1,
+                expires_at: 1,
+            },
+            2,
+        ),
+        (BridgeAction::GetLastPercent { project_id: 1, milestone_idx: 1 }, 3),
+        (BridgeAction::SetEscrow { escrow_manager: ALICE }, 4),
+        (BridgeAction::SetQuorum { threshold: 2, tolerance_bps: 0, timeout_blocks: 1 }, 5),
+        (BridgeAction::GetTally { project_id: 1, milestone_idx: 1 }, 6),
+        (BridgeAction::ExpireTally { project_id: 1, milestone_idx: 1 }, 7),
+        (BridgeAction::SetMedianRounds { window_blocks: 1, max_deviation_bps: 0 }, 8),
+        (BridgeAction::CloseRound { project_id: 1, milestone_idx: 1 }, 9),
+        (BridgeAction::AddRelayer { relayer: ALICE, label: "ci".into() }, 10),
+        (BridgeAction::RemoveRelayer { relayer: ALICE }, 11),
+        (BridgeAction::SetRelayerEnabled { relayer: ALICE, enabled: false }, 12),
+        (BridgeAction::GetRelayers { active_only: true }, 13),
+        (BridgeAction::SetRelayerKey { relayer: ALICE, key: Some(RelayerKey::Sr25519(BOB)) }, 14),
+        (BridgeAction::SubmitSignedAttestation { relayer: ALICE, attestation, signature: [0; 64] }, 15),
+    ];
+    for (action, byte) in actions {
+        assert_eq!(action_byte(&action), byte, "{action:?}");
+        round_trip(action);
+    }
+}
+
+#[test]
+fn failures_encode_as_zero_then_error_code() {
+    let errors = [
+        (Error::Unauthorized, 1),
+        (Error::InvalidPayload, 2),
+        (Error::InsufficientBalance, 3),
+        (Error::InsufficientAllowance, 4),
+        (Error::CapacityExceeded, 5),
+        (Error::Overflow, 6),
+        (Error::InvalidState, 8),
+        (Error::NotFound, 9),
+        (Error::UnknownAction, 10),
+        (Error::ExternalCallFailed, 11),
+        (Error::DisputeOpen, 12),
+        (Error::NonceReused, 13),
+        (Error::AttestationExpired, 14),
+    ];
+    for (error, code) in errors {
+        assert_eq!(TokenReply::Failed(error).encode(), [0, code]);
+        assert_eq!(EscrowReply::Failed(error).encode(), [0, code]);
+        assert_eq!(BridgeReply::Failed(error).encode(), [0, code]);
+    }
+
+    let reasons =
+        [(PolicyReason::NotMonotonic, 1), (PolicyReason::BelowMinStep, 2), (PolicyReason::TestsNotPassed, 3)];
+    for (reason, code) in reasons {
+        assert_eq!(BridgeReply::Failed(Error::PolicyViolation { reason }).encode(), [0, 7, code]);
+    }
+}
+
+#[test]
+fn success_replies_keep_their_bytes() {
+    assert_eq!(TokenReply::Done.encode(), [1]);
+    assert_eq!(EscrowReply::Done.encode(), [1]);
+    assert_eq!(BridgeReply::Done.encode(), [1]);
+    assert_eq!(TokenReply::Balance(7).encode(), [&[2][..], &7u128.to_le_bytes()].concat());
+    assert_eq!(EscrowReply::ProjectCreated { project_id: 5 }.encode(), [2, 5, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(BridgeReply::LastPercent(5000).encode(), [2, 0x88, 0x13]);
+    assert_eq!(BridgeReply::Tally(None).encode(), [3, 0]);
+}
+
+#[test]
+fn init_payloads_are_the_bare_addresses() {
+    let init = EscrowInit { treasury: ALICE, usdc_token: BOB };
+    assert_eq!(init.encode(), [ALICE, BOB].concat());
+    round_trip(init);
+
+    let init = BridgeInit { escrow_manager: ALICE };
+    assert_eq!(init.encode(), ALICE);
+    round_trip(init);
+}
+
+#[test]
+fn state_replies_round_trip() {
+    let project = Project {
+        owner: ALICE,
+        payees: vec![Payee { account: BOB, share_bps: 10000 }],
+        budget: 100_000,
+        fee: 5_000,
+        status: ProjectStatus::InProgress,
+        split: PaymentSplit { upfront_bps: 2000, progress_bps: 4000, final_bps: 4000 },
+        late_penalty_bps: 1000,
+        stream_blocks: 100,
+        upfront_pool: 19_000,
+        progress_pool: 38_000,
+        final_pool: 38_000,
+        released_upfront: 19_000,
+        released_progress: 0,
+        released_final: 0,
+        awarded: 0,
+        refunded: 0,
+        milestones: vec![Milestone {
+            weight_bps: 10000,
+            deadline_blocks: 10,
+            due_at: Some(20),
+            percent: 5000,
+            overdue_from: None,
+        }],
+        cancel_approvals: vec![BOB],
+        arbiter: None,
+        dispute: Some(Dispute { raised_by: ALICE, raised_at: 1, deadline: 2 }),
+        acceptance_deadline: None,
+        streams: vec![Stream { amount: 10, start: 1, end: 2, claimed: 0 }],
+        share_proposal: Some(ShareProposal {
+            payees: vec![Payee { account: ALICE, share_bps: 10000 }],
+            approvals: vec![ALICE],
+            proposed_by: ALICE,
+            expires_at: 30,
+        }),
+    };
+    round_trip(EscrowReply::Project(Box::new(project)));
+
+    round_trip(BridgeReply::Tally(Some(Tally { expires_at: 9, reports: vec![Report { relayer: ALICE, percent: 1 }] })));
+    round_trip(BridgeReply::Relayers(vec![Relayer {
+        account: ALICE,
+        label: "ci-runner".into(),
+        added_at: 3,
+        enabled: true,
+        key: Some(RelayerKey::Ed25519(BOB)),
+    }]));
+}
+
+#[test]
+fn signing_message_is_bridge_id_then_attestation() {
+    let attestation =
+        Attestation { project_id: 258, milestone_idx: 2, percent: 5000, tests_passed: true, nonce: 42, expires_at: 7 };
+    let mut expected = BOB.to_vec();
+    expected.extend_from_slice(&258u64.to_le_bytes());
+    expected.extend_from_slice(&[2, 0x88, 0x13, 1]);
+    expected.extend_from_slice(&42u64.to_le_bytes());
+    expected.extend_from_slice(&7u32.to_le_bytes());
+    assert_eq!(attestation.signing_message(BOB), expected);
+}
+
+#[test]
+fn trailing_bytes_are_rejected() {
+    let mut bytes = EscrowAction::FundProject { project_id: 1 }.encode();
+    bytes.push(0);
+    assert!(EscrowAction::decode_all(&mut &bytes[..]).is_err());
+}
+
+#[test]
+fn unknown_action_bytes_are_told_apart_from_malformed_payloads() {
+    assert_eq!(hvs_io::decode_action::<TokenAction>(&[42]), Err(Error::UnknownAction));
+    assert_eq!(hvs_io::decode_action::<EscrowAction>(&[0]), Err(Error::UnknownAction));
+    assert_eq!(hvs_io::decode_action::<BridgeAction>(&[1, 0, 0]), Err(Error::UnknownAction));
+
+    // A known action with missing fields, or no action byte at all, is malformed.
+    assert_eq!(hvs_io::decode_action::<EscrowAction>(&[2, 1, 0]), Err(Error::InvalidPayload));
+    assert_eq!(hvs_io::decode_action::<TokenAction>(&[]), Err(Error::InvalidPayload));
+
+    let payload = EscrowAction::FundProject { project_id: 1 }.encode();
+    assert_eq!(hvs_io::decode_action::<EscrowAction>(&payload), Ok(EscrowAction::FundProject { project_id: 1 }));
+}
@@ -71,6 +71,230 @@ function u16ToLeBytes(value) {
   return bytes;
 }
 
+function u32ToLeBytes(value) {
+  const bytes = new Uint8Array(4);
+  for (let i = 0; i < 4; i++) {
+    bytes[i] = (value >>> (8 * i)) & 0xFF;
+  }
+  return bytes;
+}
+
+function u64ToLeBytes(value) {
+  // Convert u64 (project ids) to 8-byte little-endian
+  const bytes = new Uint8Array(8);
+  let bigValue = BigInt(value);
+  for (let i = 0; i < 8; i++) {
+    bytes[i] = Number(bigValue & 0xFFn);
+    bigValue >>= 8n;
+  }
+  return bytes;
+}
+
+function leBytesToU64(bytes) {
+  let result = 0n;
+  for (let i = 7; i >= 0; i--) {
+    result = (result << 8n) | BigInt(bytes[i]);
+  }
+  return result;
+}
+
+function compactToBytes(value) {
+  // SCALE compact encoding of a length (vectors are prefixed with it)
+  if (value < 1 << 6) {
+    return new Uint8Array([value << 2]);
+  }
+  if (value < 1 << 14) {
+    const v = (value << 2) | 0b01;
+    return new Uint8Array([v & 0xFF, (v >> 8) & 0xFF]);
+  }
+  const v = ((value << 2) | 0b10) >>> 0;
+  return new Uint8Array([v & 0xFF, (v >> 8) & 0xFF, (v >> 16) & 0xFF, (v >>> 24) & 0xFF]);
+}
+
+function compactFromBytes(bytes, offset = 0) {
+  // Returns [value, byteLength] of a SCALE compact length below 2^30
+  const mode = bytes[offset] & 0b11;
+  if (mode === 0) {
+    return [bytes[offset] >> 2, 1];
+  }
+  if (mode === 1) {
+    return [(bytes[offset] | (bytes[offset + 1] << 8)) >> 2, 2];
+  }
+  const v = bytes[offset] | (bytes[offset + 1] << 8) | (bytes[offset + 2] << 16) | (bytes[offset + 3] << 24);
+  return [v >>> 2, 4];
+}
+
+function payeesToBytes(payees) {
+  // Vec<Payee>: count(compact), then (account(32 bytes), share_bps(2 bytes LE)) per payee.
+  // A single address is a one-member team with the full share.
+  const team = typeof payees === 'string' ? [{ address: payees, shareBps: 10000 }] : payees;
+  const countBytes = compactToBytes(team.length);
+  const bytes = new Uint8Array(countBytes.length + team.length * 34);
+  bytes.set(countBytes, 0);
+  team.forEach(({ address, shareBps }, i) => {
+    const offset = countBytes.length + i * 34;
+    bytes.set(addressToBytes(address), offset);
+    bytes.set(u16ToLeBytes(shareBps), offset + 32);
+  });
+  return bytes;
+}
+
+function attestationToBytes({ projectId, milestoneIndex, percentComplete, testsPassed = true, nonce, expiresAt }) {
+  // hvs_io::Attestation: project_id(8 bytes LE), milestone_index(1 byte), percent(2 bytes LE),
+  // tests_passed(1 byte), nonce(8 bytes LE), expires_at(4 bytes LE)
+  const bytes = new Uint8Array(24);
+  bytes.set(u64ToLeBytes(projectId), 0);
+  bytes[8] = milestoneIndex;
+  bytes.set(u16ToLeBytes(percentComplete), 9);
+  bytes[11] = testsPassed ? 1 : 0;
+  bytes.set(u64ToLeBytes(nonce), 12);
+  bytes.set(u32ToLeBytes(expiresAt), 20);
+  return bytes;
+}
+
+const KEY_TYPES = ['sr25519', 'ed25519'];
+
+const PROJECT_STATUSES = ['Created', 'Funded', 'InProgress', 'Completed', 'Cancelled', 'Resolved', 'Funding'];
+
+function decodeProject(bytes) {
+  // SCALE layout of hvs_io::Project
+  let offset = 0;
+  const take = (length) => {
+    const slice = bytes.slice(offset, offset + length);
+    offset += length;
+    return slice;
+  };
+  const readU16 = () => {
+    const b = take(2);
+    return (b[1] << 8) | b[0];
+  };
+  const readU32 = () => {
+    const b = take(4);
+    return (b[0] | (b[1] << 8) | (b[2] << 16) | (b[3] << 24)) >>> 0;
+  };
+  const readU128 = () => leBytesToU128(take(16));
+  const readAddress = () => bytesToHex(take(32));
+  const readOption = (read) => (take(1)[0] === 1 ? read() : null);
+  const readCompact = () => {
+    const [value, length] = compactFromBytes(bytes, offset);
+    offset += length;
+    return value;
+  };
+  const readPayees = () =>
+    Array.from({ length: readCompact() }, () => ({ address: readAddress(), shareBps: readU16() }));
+
+  const owner = readAddress();
+  const payees = readPayees();
+  const budget = readU128();
+  const fee = readU128();
+  const status = PROJECT_STATUSES[take(1)[0]];
+  const split = { upfrontBps: readU16(), progressBps: readU16(), finalBps: readU16() };
+  const latePenaltyBps = readU16();
+  const streamBlocks = readU32();
+  const upfrontPool = readU128();
+  const progressPool = readU128();
+  const finalPool = readU128();
+  const releasedUpfront = readU128();
+  const releasedProgress = readU128();
+  const releasedFinal = readU128();
+  const awarded = readU128();
+  const refunded = readU128();
+  const milestones = Array.from({ length: readCompact() }, () => ({
+    weightBps: readU16(),
+    deadlineBlocks: readU32(),
+    dueAt: readOption(readU32),
+    percent: readU16(),
+    overdueFrom: readOption(readU16)
+  }));
+  const cancelApprovals = Array.from({ length: readCompact() }, readAddress);
+  const arbiter = readOption(readAddress);
+  const dispute = readOption(() => ({ raisedBy: readAddress(), raisedAt: readU32(), deadline: readU32() }));
+  const acceptanceDeadline = readOption(readU32);
+  const streams = Array.from({ length: readCompact() }, () => ({
+    amount: readU128(),
+    start: readU32(),
+    end: readU32(),
+    claimed: readU128()
+  }));
+  const shareProposal = readOption(() => ({
+    payees: readPayees(),
+    approvals: Array.from({ length: readCompact() }, readAddress),
+    proposedBy: readAddress(),
+    expiresAt: readU32()
+  }));
+
+  return {
+    owner,
+    payees,
+    budget,
+    fee,
+    status,
+    split,
+    latePenaltyBps,
+    streamBlocks,
+    upfrontPool,
+    progressPool,
+    finalPool,
+    releasedUpfront,
+    releasedProgress,
+    releasedFinal,
+    awarded,
+    refunded,
+    milestones,
+    cancelApprovals,
+    arbiter,
+    dispute,
+    acceptanceDeadline,
+    streams,
+    shareProposal
+  };
+}
+
+// Error codes shared by all three contracts (see hvs-io/src/error.rs).
+// Failure replies are [0, code] and policy violations are [0, 7, reason].
+const ERROR_CODES = {
+  1: 'Unauthorized',
+  2: 'InvalidPayload',
+  3: 'InsufficientBalance',
+  4: 'InsufficientAllowance',
+  5: 'CapacityExceeded',
+  6: 'Overflow',
+  7: 'PolicyViolation',
+  8: 'InvalidState',
+  9: 'NotFound',
+  10: 'UnknownAction',
+  11: 'ExternalCallFailed',
+  12: 'DisputeOpen',
+  13: 'NonceReused',
+  14: 'AttestationExpired'
+};
+
+const POLICY_REASONS = {
+  1: 'NotMonotonic',
+  2: 'BelowMinStep',
+  3: 'TestsNotPassed'
+};
+
+class ContractError extends Error {
+  constructor(code, reason) {
+    const name = ERROR_CODES[code] || `Unknown(${code})`;
+    const reasonName = reason !== undefined ? POLICY_REASONS[reason] || `Unknown(${reason})` : undefined;
+    super(reasonName ? `${name}: ${reasonName}` : name);
+    this.name = 'ContractError';
+    this.code = name;
+    this.reason = reasonName;
+  }
+}
+
+function decodeReply(bytes) {
+  // Returns null for success/data replies, or a ContractError for [0, code(, reason)]
+  bytes = typeof bytes === 'string' ? hexToBytes(bytes) : new Uint8Array(bytes);
+  if ((bytes.length === 2 || bytes.length === 3) && bytes[0] === 0) {
+    return new ContractError(bytes[1], bytes.length === 3 ? bytes[2] : undefined);
+  }
+  return null;
+}
+
 // ============================================================================
 // Main API Class
 // ============================================================================
@@ -131,7 +355,9 @@ class HyperVaraStreamsAPI {
   }
 
   /**
-   * Send a message to a contract
+   * Send a message to a contract and wait for the program's reply
+   * @returns {object} { status, blockHash, messageId, reply } where reply holds the reply payload bytes
+   * @throws {ContractError} when the program replies with Failed(Error)
    */
   async sendMessage(destination, payload, keyring, value = 0) {
     // Use fixed gas limit to avoid calculation issues
@@ -145,7 +371,49 @@ class HyperVaraStreamsAPI {
       value
     });
 
+    // Replies can land in the same block as the message, so listen before sending and keep
+    // every reply until the message id is known.
+    const replies = new Map();
+    let messageId = null;
+    let blockHash = null;
+    let settled = false;
+    let unsubscribe = null;
+
     return new Promise((resolve, reject) => {
+      const settle = (error, result) => {
+        if (settled) {
+          return;
+        }
+        settled = true;
+        Promise.resolve(unsubscribe).then((unsub) => unsub && unsub());
+        error ? reject(error) : resolve(result);
+      };
+
+      const tryResolve = () => {
+        if (!blockHash || !messageId || !replies.has(messageId)) {
+          return;
+        }
+        const { payload: reply, code } = replies.get(messageId);
+        if (code.isError) {
+          return settle(new Error(`Message failed: ${code.asError.toString()}`));
+        }
+        const error = decodeReply(reply);
+        if (error) {
+          return settle(error);
+        }
+        console.log('📬 Reply received');
+        settle(null, { status: 'finalized', blockHash, messageId, reply });
+      };
+
+      unsubscribe = this.api.gearEvents.subscribeToGearEvent('UserMessageSent', ({ data: { message } }) => {
+        if (message.details.isNone) {
+          return;
+        }
+        const details = message.details.unwrap();
+        replies.set(details.to.toHex(), { payload: message.payload.toU8a(true), code: details.code });
+        tryResolve();
+      });
+
       tx.signAndSend(keyring, ({ events, status }) => {
         console.log(`📡 Transaction status: ${status.type}`);
         
@@ -159,36 +427,53 @@ class HyperVaraStreamsAPI {
             if (this.api.events.system.ExtrinsicSuccess.is(event)) {
               success = true;
             } else if (this.api.events.system.ExtrinsicFailed.is(event)) {
-              reject(new Error('Transaction failed'));
+              settle(new Error('Transaction failed'));
+            } else if (this.api.events.gear.MessageQueued.is(event)) {
+              messageId = event.data.id.toHex();
             }
           });
           
           if (success) {
-            resolve({ 
-              status: 'finalized', 
-              blockHash: status.asFinalized.toHex() 
-            });
+            blockHash = status.asFinalized.toHex();
+            tryResolve();
           }
         }
-      });
+      }).catch((error) => settle(error));
     });
   }
 
   /**
    * Read state from a contract
+   * @param {number} expectedLength - Byte length of a successful reply; any other
+   *   reply is decoded as a contract error
+   * @param {string} origin - Address the message is simulated from (default: Alice)
    */
-  async readState(programId, payload) {
+  async readState(programId, payload, expectedLength, origin = '5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY') {
     const reply = await this.api.message.calculateReply({
       destination: programId,
-      origin: decodeAddress('5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY'), // Alice default
+      origin: decodeAddress(origin),
       payload,
       gasLimit: 250000000000,
       value: 0
     });
 
+    // Surface contract error codes instead of misreading them as data
+    const bytes = reply.payload.toU8a ? reply.payload.toU8a(true) : new Uint8Array(reply.payload);
+    if (expectedLength !== undefined && bytes.length !== expectedLength) {
+      throw decodeReply(bytes) || new Error(`Unexpected reply of ${bytes.length} bytes`);
+    }
+
     return reply.payload;
   }
 
+  /**
+   * Get the current block height
+   */
+  async getBlockNumber() {
+    const header = await this.api.rpc.chain.getHeader();
+    return header.number.toNumber();
+  }
+
   async disconnect() {
     if (this.api) {
       await this.api.disconnect();
@@ -276,6 +561,222 @@ class USDCTokenAPI {
     return result;
   }
 
+  /**
+   * Approve a spender (e.g. the Escrow Manager) to transfer tokens on your behalf
+   * @param {string} spenderAddress - Spender Vara address
+   * @param {bigint|string} amount - Allowance amount (0 revokes)
+   * @param {object} ownerKeyring - Token owner keyring
+   */
+  async approve(spenderAddress, amount, ownerKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('USDC Token contract address not set');
+    }
+
+    console.log(`\n✍️  Approving ${spenderAddress} to spend ${amount} USDC...`);
+
+    // Payload: [3, spender_address(32 bytes), amount(16 bytes LE)]
+    const payload = new Uint8Array(49);
+    payload[0] = 3; // Action: APPROVE
+    
+    const spenderBytes = addressToBytes(spenderAddress);
+    payload.set(spenderBytes, 1);
+    
+    const amountBytes = u128ToLeBytes(amount);
+    payload.set(amountBytes, 33);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      ownerKeyring
+    );
+
+    console.log('✅ Allowance set!');
+    return result;
+  }
+
+  /**
+   * Transfer USDC tokens from another account using an allowance
+   * @param {string} fromAddress - Source Vara address
+   * @param {string} toAddress - Recipient Vara address
+   * @param {bigint|string} amount - Amount to transfer
+   * @param {object} spenderKeyring - Spender keyring
+   */
+  async transferFrom(fromAddress, toAddress, amount, spenderKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('USDC Token contract address not set');
+    }
+
+    console.log(`\n💸 Transferring ${amount} USDC tokens from ${fromAddress} to ${toAddress}...`);
+
+    // Payload: [4, from_address(32 bytes), to_address(32 bytes), amount(16 bytes LE)]
+    const payload = new Uint8Array(81);
+    payload[0] = 4; // Action: TRANSFER_FROM
+    
+    payload.set(addressToBytes(fromAddress), 1);
+    payload.set(addressToBytes(toAddress), 33);
+    
+    const amountBytes = u128ToLeBytes(amount);
+    payload.set(amountBytes, 65);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      spenderKeyring
+    );
+
+    console.log('✅ Transfer successful!');
+    return result;
+  }
+
+  /**
+   * Increase a spender's allowance
+   * @param {string} spenderAddress - Spender Vara address
+   * @param {bigint|string} amount - Amount to add to the allowance
+   * @param {object} ownerKeyring - Token owner keyring
+   */
+  async increaseAllowance(spenderAddress, amount, ownerKeyring) {
+    return this._changeAllowance(7, spenderAddress, amount, ownerKeyring);
+  }
+
+  /**
+   * Decrease a spender's allowance (fails if the allowance is smaller)
+   * @param {string} spenderAddress - Spender Vara address
+   * @param {bigint|string} amount - Amount to subtract from the allowance
+   * @param {object} ownerKeyring - Token owner keyring
+   */
+  async decreaseAllowance(spenderAddress, amount, ownerKeyring) {
+    return this._changeAllowance(8, spenderAddress, amount, ownerKeyring);
+  }
+
+  async _changeAllowance(action, spenderAddress, amount, ownerKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('USDC Token contract address not set');
+    }
+
+    const verb = action === 7 ? 'Increasing' : 'Decreasing';
+    console.log(`\n✍️  ${verb} allowance of ${spenderAddress} by ${amount} USDC...`);
+
+    // Payload: [7|8, spender_address(32 bytes), amount(16 bytes LE)]
+    const payload = new Uint8Array(49);
+    payload[0] = action; // Action: INCREASE_ALLOWANCE / DECREASE_ALLOWANCE
+    payload.set(addressToBytes(spenderAddress), 1);
+    payload.set(u128ToLeBytes(amount), 33);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      ownerKeyring
+    );
+
+    console.log('✅ Allowance updated!');
+    return result;
+  }
+
+  /**
+   * Get the allowance a spender has on an owner's account
+   * @param {string} ownerAddress - Token owner Vara address
+   * @param {string} spenderAddress - Spender Vara address
+   */
+  async allowance(ownerAddress, spenderAddress) {
+    if (!this.contractAddress) {
+      throw new Error('USDC Token contract address not set');
+    }
+
+    console.log(`\n🔍 Querying allowance of ${spenderAddress} on ${ownerAddress}...`);
+
+    // Payload: [6, owner_address(32 bytes), spender_address(32 bytes)]
+    const payload = new Uint8Array(65);
+    payload[0] = 6; // Action: ALLOWANCE
+    payload.set(addressToBytes(ownerAddress), 1);
+    payload.set(addressToBytes(spenderAddress), 33);
+
+    const response = await this.parent.readState(this.contractAddress, payload, 17);
+
+    // Response is TokenReply::Allowance: [3, u128 (16 bytes LE)]
+    const allowance = leBytesToU128(new Uint8Array(response).slice(1));
+
+    console.log(`💳 Allowance: ${allowance}`);
+    return allowance;
+  }
+
+  /**
+   * Burn USDC tokens held by the caller
+   * @param {bigint|string} amount - Amount to burn
+   * @param {object} holderKeyring - Token holder keyring
+   */
+  async burn(amount, holderKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('USDC Token contract address not set');
+    }
+
+    console.log(`\n🔥 Burning ${amount} USDC tokens...`);
+
+    // Payload: [9, amount(16 bytes LE)]
+    const payload = new Uint8Array(17);
+    payload[0] = 9; // Action: BURN
+    payload.set(u128ToLeBytes(amount), 1);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      holderKeyring
+    );
+
+    console.log('✅ Tokens burned!');
+    return result;
+  }
+
+  /**
+   * Burn USDC tokens from another account using an allowance
+   * @param {string} fromAddress - Token holder Vara address
+   * @param {bigint|string} amount - Amount to burn
+   * @param {object} spenderKeyring - Spender keyring
+   */
+  async burnFrom(fromAddress, amount, spenderKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('USDC Token contract address not set');
+    }
+
+    console.log(`\n🔥 Burning ${amount} USDC tokens from ${fromAddress}...`);
+
+    // Payload: [10, from_address(32 bytes), amount(16 bytes LE)]
+    const payload = new Uint8Array(49);
+    payload[0] = 10; // Action: BURN_FROM
+    payload.set(addressToBytes(fromAddress), 1);
+    payload.set(u128ToLeBytes(amount), 33);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      spenderKeyring
+    );
+
+    console.log('✅ Tokens burned!');
+    return result;
+  }
+
+  /**
+   * Get the total USDC supply
+   */
+  async totalSupply() {
+    if (!this.contractAddress) {
+      throw new Error('USDC Token contract address not set');
+    }
+
+    console.log(`\n🔍 Querying total supply...`);
+
+    // Payload: [11]
+    const payload = new Uint8Array([11]); // Action: TOTAL_SUPPLY
+
+    const response = await this.parent.readState(this.contractAddress, payload, 17);
+
+    // Response is TokenReply::TotalSupply: [4, u128 (16 bytes LE)]
+    const supply = leBytesToU128(new Uint8Array(response).slice(1));
+
+    console.log(`🪙 Total supply: ${supply}`);
+    return supply;
+  }
+
   /**
    * Get balance of an account
    * @param {string} accountAddress - Account Vara address
@@ -294,10 +795,10 @@ class USDCTokenAPI {
     const accountBytes = addressToBytes(accountAddress);
     payload.set(accountBytes, 1);
 
-    const response = await this.parent.readState(this.contractAddress, payload);
+    const response = await this.parent.readState(this.contractAddress, payload, 17);
     
-    // Response is 16 bytes representing u128
-    const balance = leBytesToU128(new Uint8Array(response));
+    // Response is TokenReply::Balance: [2, u128 (16 bytes LE)]
+    const balance = leBytesToU128(new Uint8Array(response).slice(1));
     
     console.log(`💰 Balance: ${balance}`);
     return balance;
@@ -320,26 +821,56 @@ class EscrowManagerAPI {
   /**
    * Create a new project
    * @param {bigint|string} budget - Total project budget
-   * @param {array} milestones - [milestone1%, milestone2%, milestone3%]
-   * @param {object} ownerKeyring - Project owner keyring
+   * @param {array} milestones - Per milestone, its weight in basis points (weights must sum to 10000)
+   *   or { weightBps, deadlineBlocks } where the deadline counts from developer selection
+   *   (e.g. [3000, 3000, 4000] or [{ weightBps: 5000, deadlineBlocks: 100800 }, ...])
+   * @param {object} ownerKeyring - Project owner keyring; becomes the project owner
+   * @param {object} split - Division of the budget net of the platform fee, in basis points
+   *   summing to 10000: { upfrontBps, progressBps, finalBps }. Upfront is paid at developer selection.
+   * @param {number} latePenaltyBps - Cut of progress completed after a milestone's deadline (default: 0)
+   * @param {number} streamBlocks - Blocks each progress payout vests over, claimed with `claim`
+   *   (default: 0, progress is paid as soon as it is attested)
+   * @returns {object} Transaction result with the assigned `projectId`
    */
-  async createProject(budget, milestones, ownerKeyring) {
+  async createProject(
+    budget,
+    milestones,
+    ownerKeyring,
+    split = { upfrontBps: 0, progressBps: 6000, finalBps: 4000 },
+    latePenaltyBps = 0,
+    streamBlocks = 0
+  ) {
     if (!this.contractAddress) {
       throw new Error('Escrow Manager contract address not set');
     }
 
     console.log(`\n📝 Creating project with budget ${budget}...`);
 
-    // Payload: [1, budget(16 bytes LE), m1%(2 bytes), m2%(2 bytes), m3%(2 bytes)]
-    const payload = new Uint8Array(23);
+    // Payload: EscrowAction::CreateProject
+    // [1, budget(16 bytes LE), milestone_count(compact),
+    //  (weight_bps(2 bytes LE), deadline_blocks(4 bytes LE)) per milestone,
+    //  upfront_bps(2 bytes LE), progress_bps(2 bytes LE), final_bps(2 bytes LE), late_penalty_bps(2 bytes LE),
+    //  stream_blocks(4 bytes LE)]
+    const countBytes = compactToBytes(milestones.length);
+    const splitOffset = 17 + countBytes.length + milestones.length * 6;
+    const payload = new Uint8Array(splitOffset + 12);
     payload[0] = 1; // Action: CREATE_PROJECT
     
     const budgetBytes = u128ToLeBytes(budget);
     payload.set(budgetBytes, 1);
-    
-    payload.set(u16ToLeBytes(milestones[0]), 17);
-    payload.set(u16ToLeBytes(milestones[1]), 19);
-    payload.set(u16ToLeBytes(milestones[2]), 21);
+    payload.set(countBytes, 17);
+    milestones.forEach((milestone, i) => {
+      const { weightBps, deadlineBlocks = 0 } =
+        typeof milestone === 'number' ? { weightBps: milestone } : milestone;
+      const milestoneOffset = 17 + countBytes.length + i * 6;
+      payload.set(u16ToLeBytes(weightBps), milestoneOffset);
+      payload.set(u32ToLeBytes(deadlineBlocks), milestoneOffset + 2);
+    });
+    payload.set(u16ToLeBytes(split.upfrontBps), splitOffset);
+    payload.set(u16ToLeBytes(split.progressBps), splitOffset + 2);
+    payload.set(u16ToLeBytes(split.finalBps), splitOffset + 4);
+    payload.set(u16ToLeBytes(latePenaltyBps), splitOffset + 6);
+    payload.set(u32ToLeBytes(streamBlocks), splitOffset + 8);
 
     const result = await this.parent.sendMessage(
       this.contractAddress,
@@ -347,23 +878,57 @@ class EscrowManagerAPI {
       ownerKeyring
     );
 
-    console.log('✅ Project created!');
-    return result;
+    // The id assigned to this message: EscrowReply::ProjectCreated [2, project_id(8 bytes LE)]
+    if (result.reply.length !== 9 || result.reply[0] !== 2) {
+      throw new Error('Unexpected reply to CreateProject');
+    }
+    const projectId = leBytesToU64(result.reply.slice(1));
+
+    console.log(`✅ Project ${projectId} created!`);
+    return { ...result, projectId };
+  }
+
+  /**
+   * Get a project's state
+   * @param {bigint|number} projectId - Project id
+   */
+  async getProject(projectId) {
+    if (!this.contractAddress) {
+      throw new Error('Escrow Manager contract address not set');
+    }
+
+    // Payload: [9, project_id(8 bytes LE)]
+    const payload = new Uint8Array(9);
+    payload[0] = 9; // Action: GET_PROJECT
+    payload.set(u64ToLeBytes(projectId), 1);
+
+    const response = await this.parent.readState(this.contractAddress, payload);
+    const bytes = response.toU8a ? response.toU8a(true) : new Uint8Array(response);
+    if (bytes[0] !== 3) {
+      throw decodeReply(bytes) || new Error('Unexpected reply to GetProject');
+    }
+
+    // Response is EscrowReply::Project: [3, Project]
+    return decodeProject(bytes.slice(1));
   }
 
   /**
-   * Fund the project (owner transfers USDC to escrow)
+   * Fund the project (escrow pulls the budget from the owner)
+   * The owner must first approve the Escrow Manager on the USDC token for the budget.
+   * @param {bigint|number} projectId - Project id
    * @param {object} ownerKeyring - Project owner keyring
    */
-  async fundProject(ownerKeyring) {
+  async fundProject(projectId, ownerKeyring) {
     if (!this.contractAddress) {
       throw new Error('Escrow Manager contract address not set');
     }
 
-    console.log(`\n💵 Funding project...`);
+    console.log(`\n💵 Funding project ${projectId}...`);
 
-    // Payload: [2]
-    const payload = new Uint8Array([2]); // Action: FUND_PROJECT
+    // Payload: [2, project_id(8 bytes LE)]
+    const payload = new Uint8Array(9);
+    payload[0] = 2; // Action: FUND_PROJECT
+    payload.set(u64ToLeBytes(projectId), 1);
 
     const result = await this.parent.sendMessage(
       this.contractAddress,
@@ -371,28 +936,30 @@ class EscrowManagerAPI {
       ownerKeyring
     );
 
-    console.log('✅ Project funded! (60% progress pool, 35% final pool, 5% fee)');
+    console.log('✅ Project funded! (fee routed to treasury, net budget split into pools)');
     return result;
   }
 
   /**
-   * Select developer for the project
-   * @param {string} developerAddress - Developer Vara address
+   * Select the developer, or a team, for the project
+   * @param {bigint|number} projectId - Project id
+   * @param {string|array} payees - Developer Vara address, or the team as
+   *   [{ address, shareBps }, ...] with shares summing to 10000; every release is split by share
    * @param {object} ownerKeyring - Project owner keyring
    */
-  async selectDeveloper(developerAddress, ownerKeyring) {
+  async selectDeveloper(projectId, payees, ownerKeyring) {
     if (!this.contractAddress) {
       throw new Error('Escrow Manager contract address not set');
     }
 
-    console.log(`\n👨‍💻 Selecting developer ${developerAddress}...`);
+    console.log(`\n👨‍💻 Selecting developer ${typeof payees === 'string' ? payees : `team of ${payees.length}`}...`);
 
-    // Payload: [3, developer_address(32 bytes)]
-    const payload = new Uint8Array(33);
+    // Payload: [3, project_id(8 bytes LE), payees]
+    const payeeBytes = payeesToBytes(payees);
+    const payload = new Uint8Array(9 + payeeBytes.length);
     payload[0] = 3; // Action: SELECT_DEVELOPER
-    
-    const devBytes = addressToBytes(developerAddress);
-    payload.set(devBytes, 1);
+    payload.set(u64ToLeBytes(projectId), 1);
+    payload.set(payeeBytes, 9);
 
     const result = await this.parent.sendMessage(
       this.contractAddress,
@@ -400,30 +967,32 @@ class EscrowManagerAPI {
       ownerKeyring
     );
 
-    console.log('✅ Developer selected!');
+    console.log('✅ Developer selected! Upfront payment, if any, credited.');
     return result;
   }
 
   /**
    * Apply progress update (called by authorized verifier)
-   * @param {number} milestoneIndex - 1, 2, or 3
+   * @param {bigint|number} projectId - Project id
+   * @param {number} milestoneIndex - 1-based milestone index
    * @param {number} percentComplete - Progress percentage (0-10000 bps)
    * @param {object} verifierKeyring - Verifier keyring
    */
-  async applyProgress(milestoneIndex, percentComplete, verifierKeyring) {
+  async applyProgress(projectId, milestoneIndex, percentComplete, verifierKeyring) {
     if (!this.contractAddress) {
       throw new Error('Escrow Manager contract address not set');
     }
 
     console.log(`\n📊 Applying progress: Milestone ${milestoneIndex} at ${percentComplete / 100}%...`);
 
-    // Payload: [4, milestone_index(1 byte), percent(2 bytes LE)]
-    const payload = new Uint8Array(4);
+    // Payload: [4, project_id(8 bytes LE), milestone_index(1 byte), percent(2 bytes LE)]
+    const payload = new Uint8Array(12);
     payload[0] = 4; // Action: APPLY_PROGRESS
-    payload[1] = milestoneIndex;
+    payload.set(u64ToLeBytes(projectId), 1);
+    payload[9] = milestoneIndex;
     
     const percentBytes = u16ToLeBytes(percentComplete);
-    payload.set(percentBytes, 2);
+    payload.set(percentBytes, 10);
 
     const result = await this.parent.sendMessage(
       this.contractAddress,
@@ -431,23 +1000,28 @@ class EscrowManagerAPI {
       verifierKeyring
     );
 
-    console.log('✅ Progress applied! Tokens released to developer.');
+    console.log('✅ Progress applied! Payout credited or streamed to developer.');
     return result;
   }
 
   /**
    * Mark final delivery as approved
-   * @param {object} ownerKeyring - Project owner keyring
+   * Once every milestone is complete the final pool is also released automatically when the
+   * acceptance window closes; after that anyone may call this (e.g. once a dispute has closed).
+   * @param {bigint|number} projectId - Project id
+   * @param {object} ownerKeyring - Project owner keyring (any keyring after the acceptance window)
    */
-  async markFinalApproved(ownerKeyring) {
+  async markFinalApproved(projectId, ownerKeyring) {
     if (!this.contractAddress) {
       throw new Error('Escrow Manager contract address not set');
     }
 
     console.log(`\n✅ Marking final delivery as approved...`);
 
-    // Payload: [5]
-    const payload = new Uint8Array([5]); // Action: MARK_FINAL_APPROVED
+    // Payload: [5, project_id(8 bytes LE)]
+    const payload = new Uint8Array(9);
+    payload[0] = 5; // Action: MARK_FINAL_APPROVED
+    payload.set(u64ToLeBytes(projectId), 1);
 
     const result = await this.parent.sendMessage(
       this.contractAddress,
@@ -455,133 +1029,656 @@ class EscrowManagerAPI {
       ownerKeyring
     );
 
-    console.log('✅ Final 35% released to developer!');
+    console.log('✅ Final pool released to developer!');
     return result;
   }
 
   /**
-   * Set authorized verifier (owner only)
-   * @param {string} verifierAddress - Verifier Vara address (usually verification bridge)
-   * @param {object} ownerKeyring - Project owner keyring
+   * Cancel a project and refund the unreleased remainder to the owner
+   * Before developer selection the owner cancels alone. Afterwards the owner and every
+   * payee must call this (earlier calls only record their agreement), unless the
+   * platform admin arbitrates and cancels directly. Earned but unreleased progress is paid
+   * to the team.
+   * @param {bigint|number} projectId - Project id
+   * @param {object} keyring - Owner, payee or platform admin keyring
    */
-  async setVerifier(verifierAddress, ownerKeyring) {
+  async cancelProject(projectId, keyring) {
     if (!this.contractAddress) {
       throw new Error('Escrow Manager contract address not set');
     }
 
-    console.log(`\n🔐 Setting authorized verifier to ${verifierAddress}...`);
+    console.log(`\n🛑 Cancelling project ${projectId}...`);
 
-    // Payload: [6, verifier_address(32 bytes)]
-    const payload = new Uint8Array(33);
-    payload[0] = 6; // Action: SET_VERIFIER
-    
-    const verifierBytes = addressToBytes(verifierAddress);
-    payload.set(verifierBytes, 1);
+    // Payload: [10, project_id(8 bytes LE)]
+    const payload = new Uint8Array(9);
+    payload[0] = 10; // Action: CANCEL_PROJECT
+    payload.set(u64ToLeBytes(projectId), 1);
 
     const result = await this.parent.sendMessage(
       this.contractAddress,
       payload,
-      ownerKeyring
+      keyring
     );
 
-    console.log('✅ Verifier set!');
+    console.log('✅ Cancellation submitted!');
     return result;
   }
-}
-
-// ============================================================================
-// Verification Bridge Contract API
-// ============================================================================
-
-class VerificationBridgeAPI {
-  constructor(parentApi) {
-    this.parent = parentApi;
-  }
 
-  get contractAddress() {
-    return this.parent.contracts.verificationBridge;
+  /**
+   * Credit the vested part of a streaming project's progress payouts to the team, split by
+   * the current shares (any payee); see `withdraw`
+   * @param {bigint|number} projectId - Project id
+   * @param {object} developerKeyring - Payee keyring
+   */
+  async claim(projectId, developerKeyring) {
+    console.log(`\n💧 Claiming vested payouts of project ${projectId}...`);
+    return this._sendProjectAction(20, projectId, new Uint8Array(0), developerKeyring, '✅ Vested payouts credited!');
   }
 
   /**
-   * Add a relayer (admin only)
-   * @param {number} relayerSlot - 1, 2, or 3
-   * @param {string} relayerAddress - Relayer Vara address
-   * @param {object} adminKeyring - Admin keyring
+   * Get what the developer can currently claim from a streaming project
+   * @param {bigint|number} projectId - Project id
    */
-  async setRelayer(relayerSlot, relayerAddress, adminKeyring) {
+  async getClaimable(projectId) {
     if (!this.contractAddress) {
-      throw new Error('Verification Bridge contract address not set');
+      throw new Error('Escrow Manager contract address not set');
     }
 
-    console.log(`\n🔐 Setting relayer ${relayerSlot} to ${relayerAddress}...`);
+    // Payload: [21, project_id(8 bytes LE)]
+    const payload = new Uint8Array(9);
+    payload[0] = 21; // Action: GET_CLAIMABLE
+    payload.set(u64ToLeBytes(projectId), 1);
 
-    // Payload: [1, slot(1 byte), relayer_address(32 bytes)]
-    const payload = new Uint8Array(34);
-    payload[0] = 1; // Action: SET_RELAYER
-    payload[1] = relayerSlot;
-    
-    const relayerBytes = addressToBytes(relayerAddress);
-    payload.set(relayerBytes, 2);
+    const response = await this.parent.readState(this.contractAddress, payload, 17);
 
-    const result = await this.parent.sendMessage(
-      this.contractAddress,
-      payload,
-      adminKeyring
-    );
+    // Response is EscrowReply::Claimable: [4, u128 (16 bytes LE)]
+    const claimable = leBytesToU128(new Uint8Array(response).slice(1));
 
-    console.log('✅ Relayer set!');
-    return result;
+    console.log(`💧 Claimable: ${claimable}`);
+    return claimable;
   }
 
   /**
-   * Submit attestation from Hyperliquid (relayer only)
-   * @param {number} milestoneIndex - 1, 2, or 3
-   * @param {number} percentComplete - Progress percentage (0-10000 bps)
-   * @param {object} relayerKeyring - Relayer keyring
+   * Withdraw payouts credited to the sender (upfront, progress, final and awards of every project)
+   * @param {bigint|string} amount - Amount to withdraw, at most what `getOwed` reports
+   * @param {object} keyring - Keyring of the account owed the payouts
    */
-  async submitAttestation(milestoneIndex, percentComplete, relayerKeyring) {
+  async withdraw(amount, keyring) {
     if (!this.contractAddress) {
-      throw new Error('Verification Bridge contract address not set');
+      throw new Error('Escrow Manager contract address not set');
     }
 
-    console.log(`\n🌉 Submitting attestation: Milestone ${milestoneIndex} at ${percentComplete / 100}%...`);
+    console.log(`\n🏧 Withdrawing ${amount} USDC from the escrow...`);
 
-    // Payload: [2, milestone_index(1 byte), percent(2 bytes LE)]
-    const payload = new Uint8Array(4);
-    payload[0] = 2; // Action: SUBMIT_ATTESTATION
-    payload[1] = milestoneIndex;
-    
-    const percentBytes = u16ToLeBytes(percentComplete);
-    payload.set(percentBytes, 2);
+    // Payload: [22, amount(16 bytes LE)]
+    const payload = new Uint8Array(17);
+    payload[0] = 22; // Action: WITHDRAW
+    payload.set(u128ToLeBytes(amount), 1);
 
     const result = await this.parent.sendMessage(
       this.contractAddress,
       payload,
-      relayerKeyring
+      keyring
     );
 
-    console.log('✅ Attestation submitted and forwarded to Escrow Manager!');
+    console.log('✅ Withdrawal complete!');
     return result;
   }
 
   /**
-   * Get last verified percentage for a milestone
-   * @param {number} milestoneIndex - 1, 2, or 3
+   * Get the payouts credited to an account but not yet withdrawn
+   * @param {string} address - Account Vara address
    */
-  async getLastPercent(milestoneIndex) {
+  async getOwed(address) {
     if (!this.contractAddress) {
-      throw new Error('Verification Bridge contract address not set');
+      throw new Error('Escrow Manager contract address not set');
     }
 
-    console.log(`\n🔍 Querying last percent for milestone ${milestoneIndex}...`);
+    // Payload: [23, address(32 bytes)]
+    const payload = new Uint8Array(33);
+    payload[0] = 23; // Action: GET_OWED
+    payload.set(addressToBytes(address), 1);
 
-    // Payload: [3, milestone_index(1 byte)]
-    const payload = new Uint8Array([3, milestoneIndex]);
+    const response = await this.parent.readState(this.contractAddress, payload, 17);
+
+    // Response is EscrowReply::Owed: [5, u128 (16 bytes LE)]
+    const owed = leBytesToU128(new Uint8Array(response).slice(1));
+
+    console.log(`🏧 Owed: ${owed}`);
+    return owed;
+  }
+
+  /**
+   * Propose new payee shares (owner or payee); they apply once the owner and every
+   * current payee have approved, the proposer included. Fails while another proposal
+   * is pending and has not expired
+   * @param {bigint|number} projectId - Project id
+   * @param {array} payees - [{ address, shareBps }, ...] with shares summing to 10000
+   * @param {object} keyring - Owner or payee keyring
+   */
+  async proposeShares(projectId, payees, keyring) {
+    console.log(`\n🤝 Proposing new shares for project ${projectId}...`);
+    return this._sendProjectAction(24, projectId, payeesToBytes(payees), keyring, '✅ Shares proposed!');
+  }
+
+  /**
+   * Approve the pending share proposal (owner or current payee)
+   * @param {bigint|number} projectId - Project id
+   * @param {object} keyring - Owner or payee keyring
+   */
+  async approveShares(projectId, keyring) {
+    console.log(`\n🤝 Approving proposed shares for project ${projectId}...`);
+    return this._sendProjectAction(25, projectId, new Uint8Array(0), keyring, '✅ Shares approved!');
+  }
+
+  /**
+   * Withdraw the pending share proposal (its proposer only)
+   * @param {bigint|number} projectId - Project id
+   * @param {object} keyring - Proposer keyring
+   */
+  async withdrawShares(projectId, keyring) {
+    console.log(`\n🤝 Withdrawing proposed shares for project ${projectId}...`);
+    return this._sendProjectAction(26, projectId, new Uint8Array(0), keyring, '✅ Share proposal withdrawn!');
+  }
+
+  /**
+   * Reject the delivery within the acceptance window (owner only), stopping auto-approval
+   * @param {bigint|number} projectId - Project id
+   * @param {object} ownerKeyring - Project owner keyring
+   */
+  async rejectDelivery(projectId, ownerKeyring) {
+    console.log(`\n🙅 Rejecting delivery of project ${projectId}...`);
+    return this._sendProjectAction(18, projectId, new Uint8Array(0), ownerKeyring, '✅ Delivery rejected!');
+  }
+
+  /**
+   * Raise a dispute (owner or payee); payouts freeze until it is resolved,
+   * withdrawn or expires after its deadline
+   * @param {bigint|number} projectId - Project id
+   * @param {object} keyring - Owner or payee keyring
+   */
+  async raiseDispute(projectId, keyring) {
+    console.log(`\n⚖️  Raising dispute on project ${projectId}...`);
+    return this._sendProjectAction(11, projectId, new Uint8Array(0), keyring, '✅ Dispute raised! Payouts frozen.');
+  }
+
+  /**
+   * Withdraw a dispute (the party that raised it)
+   * @param {bigint|number} projectId - Project id
+   * @param {object} keyring - Keyring of the party that raised the dispute
+   */
+  async withdrawDispute(projectId, keyring) {
+    console.log(`\n↩️  Withdrawing dispute on project ${projectId}...`);
+    return this._sendProjectAction(12, projectId, new Uint8Array(0), keyring, '✅ Dispute withdrawn!');
+  }
+
+  /**
+   * Expire a dispute the arbiter did not rule on before its deadline (anyone)
+   * @param {bigint|number} projectId - Project id
+   * @param {object} keyring - Any keyring
+   */
+  async expireDispute(projectId, keyring) {
+    console.log(`\n⌛ Expiring dispute on project ${projectId}...`);
+    return this._sendProjectAction(13, projectId, new Uint8Array(0), keyring, '✅ Dispute expired!');
+  }
+
+  /**
+   * Set the project's arbiter (owner before the developer is selected, or platform admin)
+   * @param {bigint|number} projectId - Project id
+   * @param {string} arbiterAddress - Arbiter Vara address
+   * @param {object} keyring - Owner or platform admin keyring
+   */
+  async setArbiter(projectId, arbiterAddress, keyring) {
+    console.log(`\n👩‍⚖️ Setting arbiter of project ${projectId} to ${arbiterAddress}...`);
+    return this._sendProjectAction(14, projectId, addressToBytes(arbiterAddress), keyring, '✅ Arbiter set!');
+  }
+
+  /**
+   * Rule on a dispute (arbiter, or platform admin when none is set)
+   * @param {bigint|number} projectId - Project id
+   * @param {number} developerBps - Share of the remaining funds awarded to the developer (0-10000 bps);
+   *   the rest is refunded to the owner
+   * @param {object} arbiterKeyring - Arbiter keyring
+   */
+  async resolveDispute(projectId, developerBps, arbiterKeyring) {
+    console.log(`\n⚖️  Resolving dispute on project ${projectId}: ${developerBps / 100}% to developer...`);
+    return this._sendProjectAction(15, projectId, u16ToLeBytes(developerBps), arbiterKeyring, '✅ Dispute resolved!');
+  }
+
+  async _sendProjectAction(action, projectId, args, keyring, doneMessage) {
+    if (!this.contractAddress) {
+      throw new Error('Escrow Manager contract address not set');
+    }
+
+    // Payload: [action, project_id(8 bytes LE), args...]
+    const payload = new Uint8Array(9 + args.length);
+    payload[0] = action;
+    payload.set(u64ToLeBytes(projectId), 1);
+    payload.set(args, 9);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      keyring
+    );
+
+    console.log(doneMessage);
+    return result;
+  }
+
+  /**
+   * Set how long owners have to review a completed project before the final pool
+   * is released automatically (platform admin only)
+   * @param {number} blocks - Window length in blocks (0 disables auto-approval)
+   * @param {object} adminKeyring - Platform admin keyring
+   */
+  async setAcceptanceWindow(blocks, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Escrow Manager contract address not set');
+    }
+
+    console.log(`\n⏳ Setting acceptance window to ${blocks} blocks...`);
+
+    // Payload: [17, blocks(4 bytes LE)]
+    const payload = new Uint8Array(5);
+    payload[0] = 17; // Action: SET_ACCEPTANCE_WINDOW
+    payload.set(u32ToLeBytes(blocks), 1);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Acceptance window set!');
+    return result;
+  }
+
+  /**
+   * Set authorized verifier (platform admin only)
+   * @param {string} verifierAddress - Verifier Vara address (usually verification bridge)
+   * @param {object} adminKeyring - Platform admin keyring
+   */
+  async setVerifier(verifierAddress, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Escrow Manager contract address not set');
+    }
+
+    console.log(`\n🔐 Setting authorized verifier to ${verifierAddress}...`);
+
+    // Payload: [6, verifier_address(32 bytes)]
+    const payload = new Uint8Array(33);
+    payload[0] = 6; // Action: SET_VERIFIER
+    
+    const verifierBytes = addressToBytes(verifierAddress);
+    payload.set(verifierBytes, 1);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Verifier set!');
+    return result;
+  }
+
+  /**
+   * Set the treasury that receives the platform fee (platform admin only)
+   * @param {string} treasuryAddress - Treasury Vara address
+   * @param {object} adminKeyring - Platform admin keyring
+   */
+  async setTreasury(treasuryAddress, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Escrow Manager contract address not set');
+    }
+
+    console.log(`\n🏦 Setting treasury to ${treasuryAddress}...`);
+
+    // Payload: [7, treasury_address(32 bytes)]
+    const payload = new Uint8Array(33);
+    payload[0] = 7; // Action: SET_TREASURY
+    
+    const treasuryBytes = addressToBytes(treasuryAddress);
+    payload.set(treasuryBytes, 1);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Treasury set!');
+    return result;
+  }
+
+  /**
+   * Set the platform fee taken on funding (platform admin only)
+   * @param {number} feeBps - Fee in basis points (0-10000, default 500 = 5%)
+   * @param {object} adminKeyring - Platform admin keyring
+   */
+  async setFeeBps(feeBps, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Escrow Manager contract address not set');
+    }
+
+    console.log(`\n💱 Setting platform fee to ${feeBps / 100}%...`);
+
+    // Payload: [8, fee_bps(2 bytes LE)]
+    const payload = new Uint8Array(3);
+    payload[0] = 8; // Action: SET_FEE_BPS
+    payload.set(u16ToLeBytes(feeBps), 1);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Fee set!');
+    return result;
+  }
+}
+
+// ============================================================================
+// Verification Bridge Contract API
+// ============================================================================
+
+class VerificationBridgeAPI {
+  constructor(parentApi) {
+    this.parent = parentApi;
+  }
+
+  get contractAddress() {
+    return this.parent.contracts.verificationBridge;
+  }
+
+  /**
+   * Register a relayer (admin only)
+   * @param {string} relayerAddress - Relayer Vara address
+   * @param {string} label - Human-readable name, at most 64 bytes
+   * @param {object} adminKeyring - Admin keyring
+   */
+  async addRelayer(relayerAddress, label, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🔐 Adding relayer ${label} (${relayerAddress})...`);
+
+    // Payload: BridgeAction::AddRelayer [10, relayer_address(32 bytes), label(compact length + utf-8)]
+    const labelBytes = new TextEncoder().encode(label);
+    const lengthBytes = compactToBytes(labelBytes.length);
+    const payload = new Uint8Array(33 + lengthBytes.length + labelBytes.length);
+    payload[0] = 10; // Action: ADD_RELAYER
+    payload.set(addressToBytes(relayerAddress), 1);
+    payload.set(lengthBytes, 33);
+    payload.set(labelBytes, 33 + lengthBytes.length);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Relayer added!');
+    return result;
+  }
+
+  /**
+   * Unregister a relayer (admin only)
+   * @param {string} relayerAddress - Relayer Vara address
+   * @param {object} adminKeyring - Admin keyring
+   */
+  async removeRelayer(relayerAddress, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🔐 Removing relayer ${relayerAddress}...`);
+
+    // Payload: [11, relayer_address(32 bytes)]
+    const payload = new Uint8Array(33);
+    payload[0] = 11; // Action: REMOVE_RELAYER
+    payload.set(addressToBytes(relayerAddress), 1);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Relayer removed!');
+    return result;
+  }
+
+  /**
+   * Pause or resume a relayer without unregistering it (admin only)
+   * @param {string} relayerAddress - Relayer Vara address
+   * @param {boolean} enabled - Whether the relayer may submit attestations
+   * @param {object} adminKeyring - Admin keyring
+   */
+  async setRelayerEnabled(relayerAddress, enabled, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🔐 ${enabled ? 'Enabling' : 'Disabling'} relayer ${relayerAddress}...`);
+
+    // Payload: [12, relayer_address(32 bytes), enabled(1 byte)]
+    const payload = new Uint8Array(34);
+    payload[0] = 12; // Action: SET_RELAYER_ENABLED
+    payload.set(addressToBytes(relayerAddress), 1);
+    payload[33] = enabled ? 1 : 0;
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log(`✅ Relayer ${enabled ? 'enabled' : 'disabled'}!`);
+    return result;
+  }
+
+  /**
+   * List registered relayers
+   * @param {boolean} activeOnly - Only return enabled relayers
+   * @returns {Array} [{ account, label, addedAt, enabled, key: { type, publicKey } | null }]
+   */
+  async getRelayers(activeOnly = false) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    // Payload: [13, active_only(1 byte)]
+    const payload = new Uint8Array([13, activeOnly ? 1 : 0]); // Action: GET_RELAYERS
 
     const response = await this.parent.readState(this.contractAddress, payload);
+    const bytes = response.toU8a ? response.toU8a(true) : new Uint8Array(response);
+    if (bytes[0] !== 4) {
+      throw decodeReply(bytes) || new Error('Unexpected reply to GetRelayers');
+    }
+
+    // Response is BridgeReply::Relayers: [4, Vec<(account(32), label, added_at(4 bytes LE), enabled(1 byte), key)>]
+    const [count, prefixLength] = compactFromBytes(bytes, 1);
+    let offset = 1 + prefixLength;
+    const relayers = [];
+    for (let i = 0; i < count; i++) {
+      const account = bytesToHex(bytes.slice(offset, offset + 32));
+      const [labelLength, labelPrefix] = compactFromBytes(bytes, offset + 32);
+      const labelStart = offset + 32 + labelPrefix;
+      const label = new TextDecoder().decode(bytes.slice(labelStart, labelStart + labelLength));
+      offset = labelStart + labelLength;
+      const addedAt = (bytes[offset] | (bytes[offset + 1] << 8) | (bytes[offset + 2] << 16) | (bytes[offset + 3] << 24)) >>> 0;
+      const enabled = bytes[offset + 4] === 1;
+      offset += 5;
+      // key: Option<RelayerKey>, [0] or [1, key_type(1 byte), public_key(32 bytes)]
+      let key = null;
+      if (bytes[offset] === 1) {
+        key = { type: KEY_TYPES[bytes[offset + 1]], publicKey: bytesToHex(bytes.slice(offset + 2, offset + 34)) };
+        offset += 34;
+      } else {
+        offset += 1;
+      }
+      relayers.push({ account, label, addedAt, enabled, key });
+    }
+
+    return relayers;
+  }
+
+  /**
+   * Register or clear the key a relayer signs attestations with (admin only)
+   * @param {string} relayerAddress - Relayer Vara address
+   * @param {object|null} signingKey - Keyring or { type: 'sr25519'|'ed25519', publicKey }, or null to clear
+   * @param {object} adminKeyring - Admin keyring
+   */
+  async setRelayerKey(relayerAddress, signingKey, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🔑 ${signingKey ? 'Setting' : 'Clearing'} signing key of relayer ${relayerAddress}...`);
+
+    // Payload: [14, relayer_address(32 bytes), key(Option: [0] or [1, key_type(1 byte), public_key(32 bytes)])]
+    const payload = new Uint8Array(signingKey ? 67 : 34);
+    payload[0] = 14; // Action: SET_RELAYER_KEY
+    payload.set(addressToBytes(relayerAddress), 1);
+    if (signingKey) {
+      const keyType = KEY_TYPES.indexOf(signingKey.type);
+      if (keyType < 0) {
+        throw new Error(`Unsupported key type '${signingKey.type}'`);
+      }
+      const publicKey = signingKey.publicKey;
+      payload[33] = 1;
+      payload[34] = keyType;
+      payload.set(typeof publicKey === 'string' ? hexToBytes(publicKey) : publicKey, 35);
+    }
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log(`✅ Relayer key ${signingKey ? 'set' : 'cleared'}!`);
+    return result;
+  }
+
+  /**
+   * Sign an attestation off-chain with a relayer's registered key
+   * @param {object} attestation - { projectId, milestoneIndex, percentComplete, testsPassed, nonce, expiresAt }
+   * @param {object} signingKeyring - Keyring holding the relayer's registered key
+   * @returns {string} 64-byte signature as hex
+   */
+  signAttestation(attestation, signingKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    // Signed message: bridge_program_id(32 bytes) followed by the encoded attestation
+    const message = new Uint8Array(56);
+    message.set(addressToBytes(this.contractAddress), 0);
+    message.set(attestationToBytes(attestation), 32);
+
+    return bytesToHex(signingKeyring.sign(message));
+  }
+
+  /**
+   * Submit an attestation signed by a relayer's key; any account may send it
+   * @param {string} relayerAddress - Address of the relayer whose key signed the attestation
+   * @param {object} attestation - { projectId, milestoneIndex, percentComplete, testsPassed, nonce, expiresAt }
+   * @param {string|Uint8Array} signature - Signature from signAttestation
+   * @param {object} senderKeyring - Keyring paying for the message
+   */
+  async submitSignedAttestation(relayerAddress, attestation, signature, senderKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🌉 Submitting signed attestation: Milestone ${attestation.milestoneIndex} at ${attestation.percentComplete / 100}%...`);
+
+    // Payload: [15, relayer_address(32 bytes), attestation(24 bytes), signature(64 bytes)]
+    const payload = new Uint8Array(121);
+    payload[0] = 15; // Action: SUBMIT_SIGNED_ATTESTATION
+    payload.set(addressToBytes(relayerAddress), 1);
+    payload.set(attestationToBytes(attestation), 33);
+    payload.set(typeof signature === 'string' ? hexToBytes(signature) : signature, 57);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      senderKeyring
+    );
+
+    console.log('✅ Signed attestation submitted!');
+    return result;
+  }
+
+  /**
+   * Submit attestation from Hyperliquid (relayer only)
+   * @param {bigint|number} projectId - Escrow project id
+   * @param {number} milestoneIndex - 1-based milestone index
+   * @param {number} percentComplete - Progress percentage (0-10000 bps)
+   * @param {object} relayerKeyring - Relayer keyring
+   * @param {boolean} testsPassed - Whether the milestone's tests passed (default: true)
+   * @param {bigint|number} nonce - Must exceed the relayer's previous nonce (default: current time in ms)
+   * @param {number} expiresAt - Last block the attestation is valid in (default: 600 blocks from now)
+   */
+  async submitAttestation(projectId, milestoneIndex, percentComplete, relayerKeyring, testsPassed = true,
+    nonce = Date.now(), expiresAt = undefined) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🌉 Submitting attestation: Milestone ${milestoneIndex} at ${percentComplete / 100}%...`);
+
+    if (expiresAt === undefined) {
+      expiresAt = await this.parent.getBlockNumber() + 600; // About 30 minutes of 3-second blocks
+    }
+
+    // Payload: BridgeAction::SubmitAttestation
+    // [2, project_id(8 bytes LE), milestone_index(1 byte), percent(2 bytes LE), tests_passed(1 byte),
+    //  nonce(8 bytes LE), expires_at(4 bytes LE)]
+    const payload = new Uint8Array(25);
+    payload[0] = 2; // Action: SUBMIT_ATTESTATION
+    payload.set(
+      attestationToBytes({ projectId, milestoneIndex, percentComplete, testsPassed, nonce, expiresAt }),
+      1
+    );
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      relayerKeyring
+    );
+
+    console.log('✅ Attestation submitted! Forwarded to Escrow Manager once the relayers agree.');
+    return result;
+  }
+
+  /**
+   * Get last verified percentage for a project's milestone
+   * @param {bigint|number} projectId - Escrow project id
+   * @param {number} milestoneIndex - 1-based milestone index
+   */
+  async getLastPercent(projectId, milestoneIndex) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🔍 Querying last percent for project ${projectId} milestone ${milestoneIndex}...`);
+
+    // Payload: [3, project_id(8 bytes LE), milestone_index(1 byte)]
+    const payload = new Uint8Array(10);
+    payload[0] = 3; // Action: GET_LAST_PERCENT
+    payload.set(u64ToLeBytes(projectId), 1);
+    payload[9] = milestoneIndex;
+
+    const response = await this.parent.readState(this.contractAddress, payload, 3);
     
-    // Response is 2 bytes representing u16
-    const percent = (response[1] << 8) | response[0];
+    // Response is BridgeReply::LastPercent: [2, u16 (2 bytes LE)]
+    const percent = (response[2] << 8) | response[1];
     
     console.log(`📊 Last verified: ${percent / 100}%`);
     return percent;
@@ -615,6 +1712,108 @@ class VerificationBridgeAPI {
     console.log('✅ Escrow Manager set!');
     return result;
   }
+
+  /**
+   * Require several relayers to agree before progress is forwarded (admin only)
+   * @param {number} threshold - Distinct relayers that must agree (1 forwards every valid report)
+   * @param {number} toleranceBps - Largest difference between reports counted as agreeing
+   * @param {number} timeoutBlocks - Blocks after its first report before a tally expires
+   * @param {object} adminKeyring - Admin keyring
+   */
+  async setQuorum(threshold, toleranceBps, timeoutBlocks, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n🗳️  Setting quorum to ${threshold} relayers within ${toleranceBps / 100}%...`);
+
+    // Payload: [5, threshold(1 byte), tolerance_bps(2 bytes LE), timeout_blocks(4 bytes LE)]
+    const payload = new Uint8Array(8);
+    payload[0] = 5; // Action: SET_QUORUM
+    payload[1] = threshold;
+    payload.set(u16ToLeBytes(toleranceBps), 2);
+    payload.set(u32ToLeBytes(timeoutBlocks), 4);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Quorum set!');
+    return result;
+  }
+
+  /**
+   * Aggregate reports in rounds and forward each round's median (admin only)
+   * Reports further than maxDeviationBps from the median are discarded, and at least the quorum
+   * threshold must remain. Switching between quorum mode and median rounds drops pending tallies.
+   * @param {number} windowBlocks - Round length in blocks from its first report (0 returns to quorum mode)
+   * @param {number} maxDeviationBps - Largest distance from the median a report may have to count
+   * @param {object} adminKeyring - Admin keyring
+   */
+  async setMedianRounds(windowBlocks, maxDeviationBps, adminKeyring) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    console.log(`\n📐 Setting median rounds of ${windowBlocks} blocks...`);
+
+    // Payload: [8, window_blocks(4 bytes LE), max_deviation_bps(2 bytes LE)]
+    const payload = new Uint8Array(7);
+    payload[0] = 8; // Action: SET_MEDIAN_ROUNDS
+    payload.set(u32ToLeBytes(windowBlocks), 1);
+    payload.set(u16ToLeBytes(maxDeviationBps), 5);
+
+    const result = await this.parent.sendMessage(
+      this.contractAddress,
+      payload,
+      adminKeyring
+    );
+
+    console.log('✅ Median rounds set!');
+    return result;
+  }
+
+  /**
+   * Get the reports waiting for a quorum, or the open median round, on a project's milestone
+   * @param {bigint|number} projectId - Escrow project id
+   * @param {number} milestoneIndex - 1-based milestone index
+   * @returns {object|null} { expiresAt, reports: [{ relayer, percent }] }, or null when none is pending
+   */
+  async getTally(projectId, milestoneIndex) {
+    if (!this.contractAddress) {
+      throw new Error('Verification Bridge contract address not set');
+    }
+
+    // Payload: [6, project_id(8 bytes LE), milestone_index(1 byte)]
+    const payload = new Uint8Array(10);
+    payload[0] = 6; // Action: GET_TALLY
+    payload.set(u64ToLeBytes(projectId), 1);
+    payload[9] = milestoneIndex;
+
+    const response = await this.parent.readState(this.contractAddress, payload);
+    const bytes = response.toU8a ? response.toU8a(true) : new Uint8Array(response);
+    if (bytes[0] !== 3) {
+      throw decodeReply(bytes) || new Error('Unexpected reply to GetTally');
+    }
+
+    // Response is BridgeReply::Tally: [3, Option<(expires_at(4 bytes LE), reports)>]
+    if (bytes[1] === 0) {
+      return null;
+    }
+    const expiresAt = (bytes[2] | (bytes[3] << 8) | (bytes[4] << 16) | (bytes[5] << 24)) >>> 0;
+    const [count, prefixLength] = compactFromBytes(bytes, 6);
+    const reports = Array.from({ length: count }, (_, i) => {
+      const offset = 6 + prefixLength + i * 34;
+      return {
+        relayer: bytesToHex(bytes.slice(offset, offset + 32)),
+        percent: bytes[offset + 32] | (bytes[offset + 33] << 8)
+      };
+    });
+
+    return { expiresAt, reports };
+  }
 }
 
 // ============================================================================
@@ -623,12 +1822,24 @@ class VerificationBridgeAPI {
 
 module.exports = {
   HyperVaraStreamsAPI,
+  ContractError,
+  ERROR_CODES,
+  POLICY_REASONS,
   helpers: {
+    decodeReply,
     hexToBytes,
     bytesToHex,
     addressToBytes,
     u128ToLeBytes,
     leBytesToU128,
-    u16ToLeBytes
+    u16ToLeBytes,
+    u32ToLeBytes,
+    u64ToLeBytes,
+    leBytesToU64,
+    compactToBytes,
+    compactFromBytes,
+    payeesToBytes,
+    attestationToBytes,
+    decodeProject
   }
 };
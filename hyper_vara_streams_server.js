@@ -215,6 +215,50 @@ app.post('/usdc/transfer', async (req, res, next) => {
   }
 });
 
+/**
+ * Approve a spender, by default the escrow manager so it can pull a project's budget
+ * POST /usdc/approve
+ * Body: { amount: string, ownerKeyringId: string, spenderAddress?: string }
+ */
+app.post('/usdc/approve', async (req, res, next) => {
+  try {
+    const { amount, ownerKeyringId, spenderAddress = api.contracts.escrowManager } = req.body;
+    
+    if (!amount || !ownerKeyringId || !spenderAddress) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(ownerKeyringId);
+    const result = await api.usdc.approve(spenderAddress, BigInt(amount), keyring);
+    
+    res.json({ 
+      message: 'Allowance approved',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Get how much a spender may still transfer from an owner
+ * GET /usdc/allowance/:owner/:spender
+ */
+app.get('/usdc/allowance/:owner/:spender', async (req, res, next) => {
+  try {
+    const { owner, spender } = req.params;
+    const allowance = await api.usdc.allowance(owner, spender);
+    
+    res.json({ 
+      owner,
+      spender,
+      allowance: allowance.toString()
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
 /**
  * Get USDC balance
  * GET /usdc/balance/:address
@@ -240,25 +284,37 @@ app.get('/usdc/balance/:address', async (req, res, next) => {
 /**
  * Create project
  * POST /escrow/create-project
- * Body: { budget: string, milestones: [number, number, number], ownerKeyringId: string }
+ * Body: { budget: string, ownerKeyringId: string,
+ *         milestones: (number | { weightBps: number, deadlineBlocks: number })[] (weights summing to 10000 bps),
+ *         split?: { upfrontBps: number, progressBps: number, finalBps: number } (bps summing to 10000),
+ *         latePenaltyBps?: number, streamBlocks?: number (0 pays progress in a lump sum) }
  */
 app.post('/escrow/create-project', async (req, res, next) => {
   try {
-    const { budget, milestones, ownerKeyringId } = req.body;
+    const { budget, milestones, ownerKeyringId, split, latePenaltyBps, streamBlocks } = req.body;
     
     if (!budget || !milestones || !ownerKeyringId) {
       return res.status(400).json({ error: 'Missing required fields' });
     }
     
-    if (milestones.length !== 3) {
-      return res.status(400).json({ error: 'Must provide exactly 3 milestone percentages' });
+    if (!Array.isArray(milestones) || milestones.length === 0 ||
+        milestones.reduce((sum, m) => sum + (typeof m === 'number' ? m : m.weightBps), 0) !== 10000) {
+      return res.status(400).json({ error: 'Milestone weights must sum to 10000 basis points' });
     }
     
     const keyring = validateKeyring(ownerKeyringId);
-    const result = await api.escrow.createProject(BigInt(budget), milestones, keyring);
+    const { projectId, ...result } = await api.escrow.createProject(
+      BigInt(budget),
+      milestones,
+      keyring,
+      split,
+      latePenaltyBps,
+      streamBlocks
+    );
     
     res.json({ 
       message: 'Project created successfully',
+      projectId: projectId.toString(),
       result
     });
   } catch (error) {
@@ -267,20 +323,20 @@ app.post('/escrow/create-project', async (req, res, next) => {
 });
 
 /**
- * Fund project
+ * Fund project; the owner first approves the escrow for the budget with POST /usdc/approve
  * POST /escrow/fund-project
- * Body: { ownerKeyringId: string }
+ * Body: { projectId: string, ownerKeyringId: string }
  */
 app.post('/escrow/fund-project', async (req, res, next) => {
   try {
-    const { ownerKeyringId } = req.body;
+    const { projectId, ownerKeyringId } = req.body;
     
-    if (!ownerKeyringId) {
-      return res.status(400).json({ error: 'Missing ownerKeyringId' });
+    if (!projectId || !ownerKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
     }
     
     const keyring = validateKeyring(ownerKeyringId);
-    const result = await api.escrow.fundProject(keyring);
+    const result = await api.escrow.fundProject(BigInt(projectId), keyring);
     
     res.json({ 
       message: 'Project funded successfully',
@@ -294,18 +350,24 @@ app.post('/escrow/fund-project', async (req, res, next) => {
 /**
  * Select developer
  * POST /escrow/select-developer
- * Body: { developerAddress: string, ownerKeyringId: string }
+ * Body: { projectId: string, ownerKeyringId: string,
+ *         developerAddress?: string, payees?: { address: string, shareBps: number }[] (shares summing to 10000) }
  */
 app.post('/escrow/select-developer', async (req, res, next) => {
   try {
-    const { developerAddress, ownerKeyringId } = req.body;
+    const { projectId, developerAddress, payees, ownerKeyringId } = req.body;
     
-    if (!developerAddress || !ownerKeyringId) {
+    if (!projectId || !(developerAddress || payees) || !ownerKeyringId) {
       return res.status(400).json({ error: 'Missing required fields' });
     }
     
+    if (payees && (!Array.isArray(payees) || payees.length === 0 ||
+        payees.reduce((sum, p) => sum + p.shareBps, 0) !== 10000)) {
+      return res.status(400).json({ error: 'Payee shares must sum to 10000 basis points' });
+    }
+    
     const keyring = validateKeyring(ownerKeyringId);
-    const result = await api.escrow.selectDeveloper(developerAddress, keyring);
+    const result = await api.escrow.selectDeveloper(BigInt(projectId), payees || developerAddress, keyring);
     
     res.json({ 
       message: 'Developer selected successfully',
@@ -319,18 +381,18 @@ app.post('/escrow/select-developer', async (req, res, next) => {
 /**
  * Apply progress
  * POST /escrow/apply-progress
- * Body: { milestoneIndex: number, percentComplete: number, verifierKeyringId: string }
+ * Body: { projectId: string, milestoneIndex: number, percentComplete: number, verifierKeyringId: string }
  */
 app.post('/escrow/apply-progress', async (req, res, next) => {
   try {
-    const { milestoneIndex, percentComplete, verifierKeyringId } = req.body;
+    const { projectId, milestoneIndex, percentComplete, verifierKeyringId } = req.body;
     
-    if (!milestoneIndex || percentComplete === undefined || !verifierKeyringId) {
+    if (!projectId || !milestoneIndex || percentComplete === undefined || !verifierKeyringId) {
       return res.status(400).json({ error: 'Missing required fields' });
     }
     
     const keyring = validateKeyring(verifierKeyringId);
-    const result = await api.escrow.applyProgress(milestoneIndex, percentComplete, keyring);
+    const result = await api.escrow.applyProgress(BigInt(projectId), milestoneIndex, percentComplete, keyring);
     
     res.json({ 
       message: 'Progress applied successfully',
@@ -344,18 +406,18 @@ app.post('/escrow/apply-progress', async (req, res, next) => {
 /**
  * Mark final approved
  * POST /escrow/mark-final-approved
- * Body: { ownerKeyringId: string }
+ * Body: { projectId: string, ownerKeyringId: string } (any keyring once the acceptance window has passed)
  */
 app.post('/escrow/mark-final-approved', async (req, res, next) => {
   try {
-    const { ownerKeyringId } = req.body;
+    const { projectId, ownerKeyringId } = req.body;
     
-    if (!ownerKeyringId) {
-      return res.status(400).json({ error: 'Missing ownerKeyringId' });
+    if (!projectId || !ownerKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
     }
     
     const keyring = validateKeyring(ownerKeyringId);
-    const result = await api.escrow.markFinalApproved(keyring);
+    const result = await api.escrow.markFinalApproved(BigInt(projectId), keyring);
     
     res.json({ 
       message: 'Final delivery approved',
@@ -366,20 +428,237 @@ app.post('/escrow/mark-final-approved', async (req, res, next) => {
   }
 });
 
+/**
+ * Cancel project
+ * POST /escrow/cancel-project
+ * Body: { projectId: string, keyringId: string } (owner, payee or platform admin)
+ */
+app.post('/escrow/cancel-project', async (req, res, next) => {
+  try {
+    const { projectId, keyringId } = req.body;
+    
+    if (!projectId || !keyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(keyringId);
+    const result = await api.escrow.cancelProject(BigInt(projectId), keyring);
+    
+    res.json({ 
+      message: 'Cancellation submitted',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Dispute endpoints
+ * POST /escrow/raise-dispute     Body: { projectId: string, keyringId: string } (owner or payee)
+ * POST /escrow/withdraw-dispute  Body: { projectId: string, keyringId: string } (party that raised it)
+ * POST /escrow/expire-dispute    Body: { projectId: string, keyringId: string } (anyone, after the deadline)
+ * POST /escrow/reject-delivery   Body: { projectId: string, keyringId: string } (owner, within the acceptance window)
+ * POST /escrow/claim             Body: { projectId: string, keyringId: string } (payee of a streaming project,
+ *                                credits what has vested to the team)
+ * POST /escrow/approve-shares    Body: { projectId: string, keyringId: string } (owner or current payee)
+ * POST /escrow/withdraw-shares   Body: { projectId: string, keyringId: string } (proposer of the pending shares)
+ */
+for (const [route, method, message] of [
+  ['reject-delivery', 'rejectDelivery', 'Delivery rejected'],
+  ['raise-dispute', 'raiseDispute', 'Dispute raised'],
+  ['withdraw-dispute', 'withdrawDispute', 'Dispute withdrawn'],
+  ['expire-dispute', 'expireDispute', 'Dispute expired'],
+  ['claim', 'claim', 'Vested payouts credited'],
+  ['approve-shares', 'approveShares', 'Shares approved'],
+  ['withdraw-shares', 'withdrawShares', 'Share proposal withdrawn']
+]) {
+  app.post(`/escrow/${route}`, async (req, res, next) => {
+    try {
+      const { projectId, keyringId } = req.body;
+      
+      if (!projectId || !keyringId) {
+        return res.status(400).json({ error: 'Missing required fields' });
+      }
+      
+      const keyring = validateKeyring(keyringId);
+      const result = await api.escrow[method](BigInt(projectId), keyring);
+      
+      res.json({ message, result });
+    } catch (error) {
+      next(error);
+    }
+  });
+}
+
+/**
+ * Propose new payee shares
+ * POST /escrow/propose-shares
+ * Body: { projectId: string, payees: { address: string, shareBps: number }[], keyringId: string } (owner or payee)
+ */
+app.post('/escrow/propose-shares', async (req, res, next) => {
+  try {
+    const { projectId, payees, keyringId } = req.body;
+    
+    if (!projectId || !payees || !keyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    if (!Array.isArray(payees) || payees.length === 0 ||
+        payees.reduce((sum, p) => sum + p.shareBps, 0) !== 10000) {
+      return res.status(400).json({ error: 'Payee shares must sum to 10000 basis points' });
+    }
+    
+    const keyring = validateKeyring(keyringId);
+    const result = await api.escrow.proposeShares(BigInt(projectId), payees, keyring);
+    
+    res.json({ 
+      message: 'Shares proposed',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Set arbiter
+ * POST /escrow/set-arbiter
+ * Body: { projectId: string, arbiterAddress: string, keyringId: string } (owner or platform admin)
+ */
+app.post('/escrow/set-arbiter', async (req, res, next) => {
+  try {
+    const { projectId, arbiterAddress, keyringId } = req.body;
+    
+    if (!projectId || !arbiterAddress || !keyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(keyringId);
+    const result = await api.escrow.setArbiter(BigInt(projectId), arbiterAddress, keyring);
+    
+    res.json({ 
+      message: 'Arbiter set successfully',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Resolve dispute
+ * POST /escrow/resolve-dispute
+ * Body: { projectId: string, developerBps: number, arbiterKeyringId: string }
+ */
+app.post('/escrow/resolve-dispute', async (req, res, next) => {
+  try {
+    const { projectId, developerBps, arbiterKeyringId } = req.body;
+    
+    if (!projectId || developerBps === undefined || !arbiterKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(arbiterKeyringId);
+    const result = await api.escrow.resolveDispute(BigInt(projectId), developerBps, keyring);
+    
+    res.json({ 
+      message: 'Dispute resolved',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Get project
+ * GET /escrow/project/:projectId
+ */
+app.get('/escrow/project/:projectId', async (req, res, next) => {
+  try {
+    const projectId = BigInt(req.params.projectId);
+    const project = await api.escrow.getProject(projectId);
+    
+    // u128 amounts are returned as strings
+    res.json(JSON.parse(JSON.stringify(
+      { projectId: projectId.toString(), ...project },
+      (key, value) => typeof value === 'bigint' ? value.toString() : value
+    )));
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Withdraw credited payouts
+ * POST /escrow/withdraw
+ * Body: { amount: string, keyringId: string }
+ */
+app.post('/escrow/withdraw', async (req, res, next) => {
+  try {
+    const { amount, keyringId } = req.body;
+    
+    if (!amount || !keyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(keyringId);
+    const result = await api.escrow.withdraw(BigInt(amount), keyring);
+    
+    res.json({ 
+      message: 'Withdrawal successful',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Get the payouts credited to an account but not yet withdrawn
+ * GET /escrow/owed/:address
+ */
+app.get('/escrow/owed/:address', async (req, res, next) => {
+  try {
+    const { address } = req.params;
+    const owed = await api.escrow.getOwed(address);
+    
+    res.json({ address, owed: owed.toString() });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Get the vested, unclaimed payouts of a streaming project
+ * GET /escrow/claimable/:projectId
+ */
+app.get('/escrow/claimable/:projectId', async (req, res, next) => {
+  try {
+    const projectId = BigInt(req.params.projectId);
+    const claimable = await api.escrow.getClaimable(projectId);
+    
+    res.json({ projectId: projectId.toString(), claimable: claimable.toString() });
+  } catch (error) {
+    next(error);
+  }
+});
+
 /**
  * Set verifier
  * POST /escrow/set-verifier
- * Body: { verifierAddress: string, ownerKeyringId: string }
+ * Body: { verifierAddress: string, adminKeyringId: string }
  */
 app.post('/escrow/set-verifier', async (req, res, next) => {
   try {
-    const { verifierAddress, ownerKeyringId } = req.body;
+    const { verifierAddress, adminKeyringId } = req.body;
     
-    if (!verifierAddress || !ownerKeyringId) {
+    if (!verifierAddress || !adminKeyringId) {
       return res.status(400).json({ error: 'Missing required fields' });
     }
     
-    const keyring = validateKeyring(ownerKeyringId);
+    const keyring = validateKeyring(adminKeyringId);
     const result = await api.escrow.setVerifier(verifierAddress, keyring);
     
     res.json({ 
@@ -396,23 +675,108 @@ app.post('/escrow/set-verifier', async (req, res, next) => {
 // ============================================================================
 
 /**
- * Set relayer
- * POST /bridge/set-relayer
- * Body: { relayerSlot: number, relayerAddress: string, adminKeyringId: string }
+ * Add relayer
+ * POST /bridge/add-relayer
+ * Body: { relayerAddress: string, label: string, adminKeyringId: string }
+ */
+app.post('/bridge/add-relayer', async (req, res, next) => {
+  try {
+    const { relayerAddress, label, adminKeyringId } = req.body;
+    
+    if (!relayerAddress || label === undefined || !adminKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    if (Buffer.byteLength(label, 'utf8') > 64) {
+      return res.status(400).json({ error: 'label must be at most 64 bytes' });
+    }
+    
+    const keyring = validateKeyring(adminKeyringId);
+    const result = await api.bridge.addRelayer(relayerAddress, label, keyring);
+    
+    res.json({ 
+      message: 'Relayer added successfully',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Remove relayer
+ * POST /bridge/remove-relayer
+ * Body: { relayerAddress: string, adminKeyringId: string }
  */
-app.post('/bridge/set-relayer', async (req, res, next) => {
+app.post('/bridge/remove-relayer', async (req, res, next) => {
   try {
-    const { relayerSlot, relayerAddress, adminKeyringId } = req.body;
+    const { relayerAddress, adminKeyringId } = req.body;
     
-    if (!relayerSlot || !relayerAddress || !adminKeyringId) {
+    if (!relayerAddress || !adminKeyringId) {
       return res.status(400).json({ error: 'Missing required fields' });
     }
     
     const keyring = validateKeyring(adminKeyringId);
-    const result = await api.bridge.setRelayer(relayerSlot, relayerAddress, keyring);
+    const result = await api.bridge.removeRelayer(relayerAddress, keyring);
     
     res.json({ 
-      message: 'Relayer set successfully',
+      message: 'Relayer removed successfully',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Enable or disable relayer
+ * POST /bridge/set-relayer-enabled
+ * Body: { relayerAddress: string, enabled: boolean, adminKeyringId: string }
+ */
+app.post('/bridge/set-relayer-enabled', async (req, res, next) => {
+  try {
+    const { relayerAddress, enabled, adminKeyringId } = req.body;
+    
+    if (!relayerAddress || typeof enabled !== 'boolean' || !adminKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(adminKeyringId);
+    const result = await api.bridge.setRelayerEnabled(relayerAddress, enabled, keyring);
+    
+    res.json({ 
+      message: `Relayer ${enabled ? 'enabled' : 'disabled'} successfully`,
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Set or clear relayer signing key
+ * POST /bridge/set-relayer-key
+ * Body: { relayerAddress: string, keyType?: 'sr25519' | 'ed25519', publicKey?: string, adminKeyringId: string }
+ * Omit publicKey to clear the key.
+ */
+app.post('/bridge/set-relayer-key', async (req, res, next) => {
+  try {
+    const { relayerAddress, keyType = 'sr25519', publicKey, adminKeyringId } = req.body;
+    
+    if (!relayerAddress || !adminKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    if (publicKey && !/^(0x)?[0-9a-fA-F]{64}$/.test(publicKey)) {
+      return res.status(400).json({ error: 'publicKey must be 32 bytes of hex' });
+    }
+    
+    const keyring = validateKeyring(adminKeyringId);
+    const signingKey = publicKey ? { type: keyType, publicKey } : null;
+    const result = await api.bridge.setRelayerKey(relayerAddress, signingKey, keyring);
+    
+    res.json({ 
+      message: `Relayer key ${signingKey ? 'set' : 'cleared'} successfully`,
       result
     });
   } catch (error) {
@@ -423,18 +787,28 @@ app.post('/bridge/set-relayer', async (req, res, next) => {
 /**
  * Submit attestation
  * POST /bridge/submit-attestation
- * Body: { milestoneIndex: number, percentComplete: number, relayerKeyringId: string }
+ * Body: { projectId: string, milestoneIndex: number, percentComplete: number, relayerKeyringId: string,
+ *         testsPassed?: boolean, nonce?: string, expiresAt?: number }
+ * nonce defaults to the current time in milliseconds and expiresAt to 600 blocks from now.
  */
 app.post('/bridge/submit-attestation', async (req, res, next) => {
   try {
-    const { milestoneIndex, percentComplete, relayerKeyringId } = req.body;
+    const { projectId, milestoneIndex, percentComplete, relayerKeyringId, testsPassed = true, nonce, expiresAt } = req.body;
     
-    if (!milestoneIndex || percentComplete === undefined || !relayerKeyringId) {
+    if (!projectId || !milestoneIndex || percentComplete === undefined || !relayerKeyringId) {
       return res.status(400).json({ error: 'Missing required fields' });
     }
     
     const keyring = validateKeyring(relayerKeyringId);
-    const result = await api.bridge.submitAttestation(milestoneIndex, percentComplete, keyring);
+    const result = await api.bridge.submitAttestation(
+      BigInt(projectId),
+      milestoneIndex,
+      percentComplete,
+      keyring,
+      testsPassed,
+      nonce === undefined ? Date.now() : BigInt(nonce),
+      expiresAt
+    );
     
     res.json({ 
       message: 'Attestation submitted successfully',
@@ -445,16 +819,56 @@ app.post('/bridge/submit-attestation', async (req, res, next) => {
   }
 });
 
+/**
+ * Submit signed attestation
+ * POST /bridge/submit-signed-attestation
+ * Body: { relayerAddress: string, projectId: string, milestoneIndex: number, percentComplete: number,
+ *         testsPassed: boolean, nonce: string, expiresAt: number, signature: string, senderKeyringId: string }
+ */
+app.post('/bridge/submit-signed-attestation', async (req, res, next) => {
+  try {
+    const {
+      relayerAddress, projectId, milestoneIndex, percentComplete, testsPassed = true,
+      nonce, expiresAt, signature, senderKeyringId
+    } = req.body;
+    
+    if (!relayerAddress || !projectId || !milestoneIndex || percentComplete === undefined ||
+        nonce === undefined || expiresAt === undefined || !signature || !senderKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(senderKeyringId);
+    const attestation = {
+      projectId: BigInt(projectId),
+      milestoneIndex,
+      percentComplete,
+      testsPassed,
+      nonce: BigInt(nonce),
+      expiresAt
+    };
+    const result = await api.bridge.submitSignedAttestation(relayerAddress, attestation, signature, keyring);
+    
+    res.json({ 
+      message: 'Signed attestation submitted successfully',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
 /**
  * Get last percent
- * GET /bridge/last-percent/:milestoneIndex
+ * GET /bridge/last-percent/:projectId/:milestoneIndex
  */
-app.get('/bridge/last-percent/:milestoneIndex', async (req, res, next) => {
+app.get('/bridge/last-percent/:projectId/:milestoneIndex', async (req, res, next) => {
   try {
+    const projectId = BigInt(req.params.projectId);
     const milestoneIndex = parseInt(req.params.milestoneIndex);
-    const percent = await api.bridge.getLastPercent(milestoneIndex);
+    const percent = await api.bridge.getLastPercent(projectId, milestoneIndex);
     
     res.json({ 
+      projectId: projectId.toString(),
       milestoneIndex,
       percent,
       percentFormatted: `${percent / 100}%`
@@ -489,6 +903,94 @@ app.post('/bridge/set-escrow-manager', async (req, res, next) => {
   }
 });
 
+/**
+ * Set relayer quorum
+ * POST /bridge/set-quorum
+ * Body: { threshold: number, toleranceBps: number, timeoutBlocks: number, adminKeyringId: string }
+ */
+app.post('/bridge/set-quorum', async (req, res, next) => {
+  try {
+    const { threshold, toleranceBps = 0, timeoutBlocks, adminKeyringId } = req.body;
+    
+    if (!threshold || !timeoutBlocks || !adminKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(adminKeyringId);
+    const result = await api.bridge.setQuorum(threshold, toleranceBps, timeoutBlocks, keyring);
+    
+    res.json({ 
+      message: 'Quorum set successfully',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Set median rounds
+ * POST /bridge/set-median-rounds
+ * Body: { windowBlocks: number (0 returns to quorum mode), maxDeviationBps: number, adminKeyringId: string }
+ */
+app.post('/bridge/set-median-rounds', async (req, res, next) => {
+  try {
+    const { windowBlocks, maxDeviationBps = 0, adminKeyringId } = req.body;
+    
+    if (windowBlocks === undefined || !adminKeyringId) {
+      return res.status(400).json({ error: 'Missing required fields' });
+    }
+    
+    const keyring = validateKeyring(adminKeyringId);
+    const result = await api.bridge.setMedianRounds(windowBlocks, maxDeviationBps, keyring);
+    
+    res.json({ 
+      message: 'Median rounds set successfully',
+      result
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * Get pending tally
+ * GET /bridge/tally/:projectId/:milestoneIndex
+ */
+app.get('/bridge/tally/:projectId/:milestoneIndex', async (req, res, next) => {
+  try {
+    const projectId = BigInt(req.params.projectId);
+    const milestoneIndex = parseInt(req.params.milestoneIndex);
+    const tally = await api.bridge.getTally(projectId, milestoneIndex);
+    
+    res.json({ 
+      projectId: projectId.toString(),
+      milestoneIndex,
+      tally
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
+/**
+ * List relayers
+ * GET /bridge/relayers?activeOnly=true
+ */
+app.get('/bridge/relayers', async (req, res, next) => {
+  try {
+    const activeOnly = req.query.activeOnly === 'true';
+    const relayers = await api.bridge.getRelayers(activeOnly);
+    
+    res.json({ 
+      activeOnly,
+      relayers
+    });
+  } catch (error) {
+    next(error);
+  }
+});
+
 // ============================================================================
 // Start Server
 // ============================================================================
@@ -505,12 +1007,34 @@ app.listen(PORT, () => {
   console.log(`   GET  /keyring/list - List keyrings`);
   console.log(`   POST /usdc/mint - Mint tokens`);
   console.log(`   POST /usdc/transfer - Transfer tokens`);
+  console.log(`   POST /usdc/approve - Approve spender`);
+  console.log(`   GET  /usdc/allowance/:owner/:spender - Get allowance`);
   console.log(`   GET  /usdc/balance/:address - Get balance`);
   console.log(`   POST /escrow/create-project - Create project`);
   console.log(`   POST /escrow/fund-project - Fund project`);
   console.log(`   POST /escrow/select-developer - Select developer`);
   console.log(`   POST /escrow/apply-progress - Apply progress`);
   console.log(`   POST /escrow/mark-final-approved - Mark final approved`);
+  console.log(`   POST /escrow/cancel-project - Cancel project`);
+  console.log(`   POST /escrow/raise-dispute - Raise dispute`);
+  console.log(`   POST /escrow/resolve-dispute - Resolve dispute`);
+  console.log(`   POST /escrow/claim - Claim vested payouts`);
+  console.log(`   POST /escrow/withdraw - Withdraw credited payouts`);
+  console.log(`   POST /escrow/propose-shares - Propose payee shares`);
+  console.log(`   POST /escrow/approve-shares - Approve payee shares`);
+  console.log(`   POST /escrow/withdraw-shares - Withdraw proposed payee shares`);
+  console.log(`   GET  /escrow/project/:projectId - Get project`);
+  console.log(`   GET  /escrow/claimable/:projectId - Get claimable payouts`);
+  console.log(`   GET  /escrow/owed/:address - Get owed payouts`);
+  console.log(`   POST /bridge/add-relayer - Add relayer`);
+  console.log(`   POST /bridge/remove-relayer - Remove relayer`);
+  console.log(`   POST /bridge/set-relayer-enabled - Enable or disable relayer`);
+  console.log(`   POST /bridge/set-relayer-key - Set relayer signing key`);
   console.log(`   POST /bridge/submit-attestation - Submit attestation`);
+  console.log(`   POST /bridge/submit-signed-attestation - Submit signed attestation`);
+  console.log(`   POST /bridge/set-quorum - Set relayer quorum`);
+  console.log(`   POST /bridge/set-median-rounds - Set median rounds`);
+  console.log(`   GET  /bridge/tally/:projectId/:milestoneIndex - Get pending tally`);
+  console.log(`   GET  /bridge/relayers - List relayers`);
   console.log(`\n✅ Ready to accept requests!\n`);
 });
@@ -1,7 +1,10 @@
 #
 ![no_std]
 
-use gstd::{msg, prelude::*, ActorId};
+use core::ptr::addr_of_mut;
+
+use gstd::{collections::BTreeMap, msg, prelude::*, ActorId};
+use hvs_io::{decode_action, Error, TokenAction, TokenEvent, TokenReply};
 
 // The contract's state is stored in static mutable variables.
 // This is a simple approach required for this specific problem, avoiding complex state management.
@@ -11,101 +14,102 @@ static mut ADMIN: [u8; 32] = [0; 32];
 // The total number of tokens in circulation.
 static mut TOTAL_SUPPLY: u128 = 0;
 
-// A fixed-size array-like structure to store account data.
-// This is intentionally simple and limited to 5 accounts as per the user request.
-static mut ACCOUNT_1: [u8; 32] = [0; 32];
-static mut BALANCE_1: u128 = 0;
-static mut ACCOUNT_2: [u8; 32] = [0; 32];
-static mut BALANCE_2: u128 = 0;
-static mut ACCOUNT_3: [u8; 32] = [0; 32];
-static mut BALANCE_3: u128 = 0;
-static mut ACCOUNT_4: [u8; 32] = [0; 32];
-static mut BALANCE_4: u128 = 0;
-static mut ACCOUNT_5: [u8; 32] = [0; 32];
-static mut BALANCE_5: u128 = 0;
-
-// Constants for reply messages.
-const SUCCESS_REPLY: [u8; 1] = [1];
-const FAILURE_REPLY: [u8; 1] = [0];
-
-// Helper function to find an account's slot index (1-5)
-.
-// Returns 0 if the account is not found.
-unsafe fn find_account_slot(account_id: &[u8; 32]) -> u8 {
-    if *account_id == ACCOUNT_1 && *account_id != [0u8; 32] {
-        return 1;
-    }
-    if *account_id == ACCOUNT_2 && *account_id != [0u8; 32] {
-        return 2;
-    }
-    if *account_id == ACCOUNT_3 && *account_id != [0u8; 32] {
-        return 3;
-    }
-    if *account_id == ACCOUNT_4 && *account_id != [0u8; 32] {
-        return 4;
-    }
-    if *account_id == ACCOUNT_5 && *account_id != [0u8; 32] {
-        return 5;
-    }
-    0 // Not found
+// Balances of all holders. Accounts are removed once their balance drops to zero,
+// so the ledger only ever holds accounts with funds and has no holder limit.
+static mut BALANCES: BTreeMap<[u8; 32], u128> = BTreeMap::new();
+
+// Allowances granted by an owner to a spender, keyed by (owner, spender).
+static mut ALLOWANCES: BTreeMap<([u8; 32], [u8; 32]), u128> = BTreeMap::new();
+
+// The zero address; tokens sent there could never be spent, so it is rejected as a recipient or spender.
+const ZERO_ACCOUNT: [u8; 32] = [0; 32];
+
+// Emits an event by sending it to the zero address, following Gear's event convention.
+fn emit_event(event: TokenEvent) {
+    msg::send(ActorId::zero(), event, 0).expect("Failed to emit event");
+}
+
+// The ledgers are reached through raw pointers so no reference to a `static mut` is taken directly.
+unsafe fn balances() -> &'static mut BTreeMap<[u8; 32], u128> {
+    &mut *addr_of_mut!(BALANCES)
+}
+
+unsafe fn allowances() -> &'static mut BTreeMap<([u8; 32], [u8; 32]), u128> {
+    &mut *addr_of_mut!(ALLOWANCES)
+}
+
+// Helper function to get an account's balance. Unknown accounts hold zero.
+unsafe fn balance_of(account_id: &[u8; 32]) -> u128 {
+    balances().get(account_id).copied().unwrap_or(0)
 }
 
-// Helper function to find an existing account or create a new one in an empty slot.
-// Returns the slot index (1-5) or 0 if no empty slots are available.
-unsafe fn find_or_create_account_slot(account_id: &[u8; 32]) -> u8 {
-    let existing_slot = find_account_slot(account_id);
-    if existing_slot != 0 {
-        return existing_slot;
+// Helper function to store an account's balance, reclaiming the entry when it reaches zero.
+unsafe fn set_balance(account_id: &[u8; 32], balance: u128) {
+    if balance == 0 {
+        balances().remove(account_id);
+    } else {
+        balances().insert(*account_id, balance);
     }
+}
+
+// Helper function to get the amount `spender` may still transfer from `owner`.
+unsafe fn allowance_of(owner: &[u8; 32], spender: &[u8; 32]) -> u128 {
+    allowances().get(&(*owner, *spender)).copied().unwrap_or(0)
+}
 
-    // Find an empty slot and assign the account to it.
-    if ACCOUNT_1 == [0u8; 32] {
-        ACCOUNT_1 = *account_id;
-        return 1;
+// Helper function to store an allowance, removing the entry when it reaches zero.
+unsafe fn set_allowance(owner: &[u8; 32], spender: &[u8; 32], amount: u128) {
+    if amount == 0 {
+        allowances().remove(&(*owner, *spender));
+    } else {
+        allowances().insert((*owner, *spender), amount);
     }
-    if ACCOUNT_2 == [0u8; 32] {
-        ACCOUNT_2 = *account_id;
-        return 2;
+}
+
+// Helper function to spend `amount` of the allowance `spender` holds on `owner`.
+// An account acting on its own tokens needs no allowance, as in Gear's VFT.
+unsafe fn spend_allowance(owner: &[u8; 32], spender: &[u8; 32], amount: u128) -> Result<(), Error> {
+    if owner == spender {
+        return Ok(());
     }
-    if ACCOUNT_3 == [0u8; 32] {
-        ACCOUNT_3 = *account_id;
-        return 3;
+
+    let allowance = allowance_of(owner, spender);
+    if allowance < amount {
+        return Err(Error::InsufficientAllowance);
     }
-    if ACCOUNT_4 == [0u8; 32] {
-        ACCOUNT_4 = *account_id;
-        return 4;
+    set_allowance(owner, spender, allowance - amount);
+    Ok(())
+}
+
+// Helper function to move tokens between two accounts.
+// Leaves both balances untouched on insufficient balance or overflow.
+unsafe fn move_tokens(from: &[u8; 32], to: &[u8; 32], amount: u128) -> Result<(), Error> {
+    let from_balance = balance_of(from);
+    if from_balance < amount {
+        return Err(Error::InsufficientBalance);
     }
-    if ACCOUNT_5 == [0u8; 32] {
-        ACCOUNT_5 = *account_id;
-        return 5;
+    if from == to {
+        return Ok(());
     }
 
-    0 // No empty slots available
+    let new_to_balance = balance_of(to).checked_add(amount).ok_or(Error::Overflow)?;
+    set_balance(from, from_balance - amount);
+    set_balance(to, new_to_balance);
+    Ok(())
 }
 
-// Helper function to get a mutable reference to a balance based on its slot.
-// Panics if the slot is invalid (should be checked before calling).
-unsafe fn get_balance_mut_by_slot(slot: u8) -> &'static mut u128 {
-    match slot {
-        1 => &mut BALANCE_1,
-        2 => &mut BALANCE_2,
-        3 => &mut BALANCE_3,
-        4 => &mut BALANCE_4,
-        5 => &mut BALANCE_5,
-        _ => panic!("Invalid account slot"),
+// Helper function to destroy tokens held by an account, reducing the total supply.
+// Leaves state untouched on insufficient balance.
+unsafe fn burn_tokens(from: &[u8; 32], amount: u128) -> Result<(), Error> {
+    let balance = balance_of(from);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
     }
-}
 
-// Helper function to get a copy of a balance based on its slot.
-unsafe fn get_balance_by_slot(slot: u8) -> u128 {
-    match slot {
-        1 => BALANCE_1,
-        2 => BALANCE_2,
-        3 => BALANCE_3,
-        4 => BALANCE_4,
-        5 => BALANCE_5,
-        _ => 0,
-    }
+    // The total supply always covers every balance, so this cannot underflow.
+    set_balance(from, balance - amount);
+    TOTAL_SUPPLY -= amount;
+    Ok(())
 }
 
 #[no_mangle]
@@ -116,136 +120,130 @@ extern "C" fn init() {
         TOTAL_SUPPLY = 0;
     }
     // Reply with success message.
-    msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Failed to reply in init");
+    msg::reply(TokenReply::Done, 0).expect("Failed to reply in init");
 }
 
 #[no_mangle]
 extern "C" fn handle() {
-    // Load the incoming message payload as bytes.
+    // Load and decode the incoming action; trailing bytes are rejected.
     let payload = msg::load_bytes().expect("Failed to load payload");
-    if payload.is_empty() {
-        // Ignore empty payloads.
-        return;
-    }
+    let reply =
+        decode_action::<TokenAction>(&payload).and_then(|action| unsafe { process(action, msg::source().into()) });
 
-    let action = payload[0];
-    let source_id: [u8; 32] = msg::source().into();
+    msg::reply(reply.unwrap_or_else(TokenReply::Failed), 0).expect("Reply failed");
+}
 
-    unsafe {
-        match action {
-            // Action 1: Mint new tokens
-            // Payload: [1, to_address(32 bytes), amount(16 bytes LE)]
-            1 => {
-                // Only the admin can mint tokens.
-                if source_id != ADMIN {
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                    return;
-                }
-
-                // Check payload length: 1 (action) + 32 (address) + 16 (amount) = 49
-                if payload.len() != 49 {
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                    return;
-                }
-
-                let to_account: [u8; 32] = payload[1..33].try_into().expect("Invalid 'to' address");
-                let amount = u128::from_le_bytes(payload[33..49].try_into().expect("Invalid amount"));
-
-                let slot = find_or_create_account_slot(&to_account);
-                if slot == 0 {
-                    // No empty slots available for the new account.
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                    return;
-                }
-
-                let balance = get_balance_mut_by_slot(slot);
-                
-                // Use checked arithmetic to prevent overflows.
-                if let (Some(new_balance), Some(new_total_supply)) =
-                    (balance.checked_add(amount), TOTAL_SUPPLY.checked_add(amount))
-                {
-                    *balance = new_balance;
-                    TOTAL_SUPPLY = new_total_supply;
-                    msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
-                } else {
-                    // Overflow occurred.
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                }
+// Executes a single action on behalf of `source_id`.
+unsafe fn process(action: TokenAction, source_id: [u8; 32]) -> Result<TokenReply, Error> {
+    match action {
+        // Mint new tokens. Only the admin can mint.
+        TokenAction::Mint { to, amount } => {
+            if source_id != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            if to == ZERO_ACCOUNT {
+                return Err(Error::InvalidPayload);
             }
 
-            // Action 2: Transfer tokens from sender to another account
-            // Payload: [2, to_address(32 bytes), amount(16 bytes LE)]
-            2 => {
-                // Check payload length: 1 (action) + 32 (address) + 16 (amount) = 49
-                if payload.len() != 49 {
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                    return;
-                }
-                
-                let to_account: [u8; 32] = payload[1..33].try_into().expect("Invalid 'to' address");
-                let amount = u128::from_le_bytes(payload[33..49].try_into().expect("Invalid amount"));
-
-                // Find sender's account slot.
-                let sender_slot = find_account_slot(&source_id);
-                if sender_slot == 0 {
-                    // Sender account does not exist.
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                    return;
-                }
-                
-                // Check for sufficient balance.
-                let sender_balance_ref = get_balance_mut_by_slot(sender_slot);
-                if *sender_balance_ref < amount {
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                    return;
-                }
-
-                // Find or create recipient's account slot.
-                let recipient_slot = find_or_create_account_slot(&to_account);
-                if recipient_slot == 0 {
-                    // No empty slots for recipient.
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                    return;
-                }
-                
-                // Get recipient's balance reference.
-                let recipient_balance_ref = get_balance_mut_by_slot(recipient_slot);
-
-                // Perform the transfer using checked arithmetic.
-                if let (Some(new_sender_balance), Some(new_recipient_balance)) =
-                    (sender_balance_ref.checked_sub(amount), recipient_balance_ref.checked_add(amount))
-                {
-                    *sender_balance_ref = new_sender_balance;
-                    *recipient_balance_ref = new_recipient_balance;
-                    msg::reply_bytes(&SUCCESS_REPLY, 0).expect("Reply failed");
-                } else {
-                    // This case should be rare if checks are done properly but is a safeguard.
-                    msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
-                }
+            // Use checked arithmetic to prevent overflows.
+            let new_balance = balance_of(&to).checked_add(amount).ok_or(Error::Overflow)?;
+            let new_total_supply = TOTAL_SUPPLY.checked_add(amount).ok_or(Error::Overflow)?;
+
+            set_balance(&to, new_balance);
+            TOTAL_SUPPLY = new_total_supply;
+            emit_event(TokenEvent::Mint { to, amount });
+        }
+
+        // Transfer tokens from sender to another account.
+        TokenAction::Transfer { to, amount } => {
+            if to == ZERO_ACCOUNT {
+                return Err(Error::InvalidPayload);
             }
 
-            // Action 5: Query the balance of an account
-            // Payload: [5, account_address(32 bytes)]
-            5 => {
-                // Check payload length: 1 (action) + 32 (address) = 33
-                if payload.len() != 33 {
-                   msg::reply_bytes(&[0u8; 16], 0).expect("Reply failed");
-                   return;
-                }
-                
-                let account_to_query: [u8; 32] = payload[1..33].try_into().expect("Invalid account address");
-                
-                let slot = find_account_slot(&account_to_query);
-                let balance = get_balance_by_slot(slot);
-                
-                // Reply with the balance as 16 little-endian bytes.
-                msg::reply_bytes(&balance.to_le_bytes(), 0).expect("Reply failed");
+            move_tokens(&source_id, &to, amount)?;
+            emit_event(TokenEvent::Transfer { from: source_id, to, amount });
+        }
+
+        // Approve a spender to transfer tokens on behalf of the sender.
+        // Overwrites any previous allowance; an amount of 0 revokes it.
+        TokenAction::Approve { spender, amount } => {
+            // Approving yourself or the zero address is meaningless, as in Gear's VFT.
+            if spender == source_id || spender == ZERO_ACCOUNT {
+                return Err(Error::InvalidPayload);
             }
 
-            _ => {
-                // Unknown action, reply with failure.
-                msg::reply_bytes(&FAILURE_REPLY, 0).expect("Reply failed");
+            set_allowance(&source_id, &spender, amount);
+            emit_event(TokenEvent::Approval { owner: source_id, spender, allowance: amount });
+        }
+
+        // Transfer tokens from an account using the sender's allowance.
+        TokenAction::TransferFrom { from, to, amount } => {
+            if to == ZERO_ACCOUNT {
+                return Err(Error::InvalidPayload);
             }
+
+            // Check the balance first so a failed move never consumes allowance.
+            if balance_of(&from) < amount {
+                return Err(Error::InsufficientBalance);
+            }
+            if from != to {
+                balance_of(&to).checked_add(amount).ok_or(Error::Overflow)?;
+            }
+
+            spend_allowance(&from, &source_id, amount)?;
+            move_tokens(&from, &to, amount)?;
+            emit_event(TokenEvent::Transfer { from, to, amount });
         }
+
+        // Query the balance of an account.
+        TokenAction::BalanceOf { account } => return Ok(TokenReply::Balance(balance_of(&account))),
+
+        // Query the allowance of a spender on an owner's account.
+        TokenAction::Allowance { owner, spender } => {
+            return Ok(TokenReply::Allowance(allowance_of(&owner, &spender)));
+        }
+
+        // Increase a spender's allowance on the sender's account.
+        TokenAction::IncreaseAllowance { spender, amount } => {
+            if spender == source_id || spender == ZERO_ACCOUNT {
+                return Err(Error::InvalidPayload);
+            }
+
+            let new_allowance = allowance_of(&source_id, &spender).checked_add(amount).ok_or(Error::Overflow)?;
+            set_allowance(&source_id, &spender, new_allowance);
+            emit_event(TokenEvent::Approval { owner: source_id, spender, allowance: new_allowance });
+        }
+
+        // Decrease a spender's allowance on the sender's account.
+        // Fails rather than saturating if the allowance is smaller than the decrease.
+        TokenAction::DecreaseAllowance { spender, amount } => {
+            let new_allowance = allowance_of(&source_id, &spender)
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientAllowance)?;
+            set_allowance(&source_id, &spender, new_allowance);
+            emit_event(TokenEvent::Approval { owner: source_id, spender, allowance: new_allowance });
+        }
+
+        // Burn tokens held by the sender.
+        TokenAction::Burn { amount } => {
+            burn_tokens(&source_id, amount)?;
+            emit_event(TokenEvent::Burn { from: source_id, amount });
+        }
+
+        // Burn tokens from an account using the sender's allowance.
+        TokenAction::BurnFrom { from, amount } => {
+            if balance_of(&from) < amount {
+                return Err(Error::InsufficientBalance);
+            }
+
+            spend_allowance(&from, &source_id, amount)?;
+            burn_tokens(&from, amount)?;
+            emit_event(TokenEvent::Burn { from, amount });
+        }
+
+        // Query the total supply.
+        TokenAction::TotalSupply => return Ok(TokenReply::TotalSupply(TOTAL_SUPPLY)),
     }
-}
\ No newline at end of file
+
+    Ok(TokenReply::Done)
+}
@@ -0,0 +1,139 @@
+//! Deploys the token, the escrow and the bridge in a gtest `System` with one project in progress.
+//!
+//! The token and the escrow are loaded from their own builds, so run `cargo build --release` in
+//! `usdc_token/` and `escrow_manager/` first.
+
+#![allow(dead_code)]
+
+use gstd::ActorId;
+use gtest::{Program, System};
+use hvs_io::{
+    BridgeAction, BridgeInit, BridgeReply, Decode, Encode, EscrowAction, EscrowInit, EscrowReply, MilestoneSpec,
+    Payee, PaymentSplit, ProjectId, Tally, TokenAction, TokenReply,
+};
+
+pub const ADMIN: u64 = 10;
+pub const OWNER: u64 = 11;
+pub const DEV: u64 = 12;
+pub const TREASURY: u64 = 13;
+pub const RELAYER_1: u64 = 21;
+pub const RELAYER_2: u64 = 22;
+pub const RELAYER_3: u64 = 23;
+
+const BUDGET: u128 = 100_000;
+const TOKEN_WASM: &str = "../usdc_token/target/wasm32-unknown-unknown/release/usdc_token.opt.wasm";
+const ESCROW_WASM: &str = "../escrow_manager/target/wasm32-unknown-unknown/release/escrow_manager.opt.wasm";
+// Covers the gas of every message a test user sends.
+const GAS_FUNDS: u128 = 1_000_000_000_000_000;
+
+pub fn account(id: u64) -> [u8; 32] {
+    ActorId::from(id).into()
+}
+
+/// Sends `payload` from `from`, runs the block and returns the program's decoded reply.
+pub fn call<R: Decode>(sys: &System, program: &Program<'_>, from: u64, payload: impl Encode) -> R {
+    let message_id = program.send_bytes(from, payload.encode());
+    let result = sys.run_next_block();
+    let reply = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(message_id))
+        .expect("the program did not reply");
+    R::decode(&mut reply.payload()).expect("the reply does not decode")
+}
+
+/// Runs `blocks` blocks, delivering the delayed messages that fall due in them.
+pub fn run_blocks(sys: &System, blocks: u32) {
+    for _ in 0..blocks {
+        sys.run_next_block();
+    }
+}
+
+/// Deploys the three programs with the bridge as the escrow's verifier and three enabled relayers,
+/// and returns the escrow, the bridge and a project with one milestone in progress.
+pub fn deploy(sys: &System) -> (Program<'_>, Program<'_>, ProjectId) {
+    for user in [ADMIN, OWNER, DEV, RELAYER_1, RELAYER_2, RELAYER_3] {
+        sys.mint_to(user, GAS_FUNDS);
+    }
+
+    let token = Program::from_file(sys, TOKEN_WASM);
+    assert_eq!(call::<TokenReply>(sys, &token, ADMIN, ()), TokenReply::Done);
+    let mint = TokenAction::Mint { to: account(OWNER), amount: BUDGET };
+    assert_eq!(call::<TokenReply>(sys, &token, ADMIN, mint), TokenReply::Done);
+
+    let escrow = Program::from_file(sys, ESCROW_WASM);
+    let init = EscrowInit { treasury: account(TREASURY), usdc_token: token.id().into() };
+    assert_eq!(call::<EscrowReply>(sys, &escrow, ADMIN, init), EscrowReply::Done);
+
+    let bridge = Program::current(sys);
+    let init = BridgeInit { escrow_manager: escrow.id().into() };
+    assert_eq!(call::<BridgeReply>(sys, &bridge, ADMIN, init), BridgeReply::Done);
+    let set_verifier = EscrowAction::SetVerifier { verifier: bridge.id().into() };
+    assert_eq!(call::<EscrowReply>(sys, &escrow, ADMIN, set_verifier), EscrowReply::Done);
+    for (relayer, label) in [(RELAYER_1, "ci-1"), (RELAYER_2, "ci-2"), (RELAYER_3, "ci-3")] {
+        let add = BridgeAction::AddRelayer { relayer: account(relayer), label: label.into() };
+        assert_eq!(call::<BridgeReply>(sys, &bridge, ADMIN, add), BridgeReply::Done);
+    }
+
+    let approve = TokenAction::Approve { spender: escrow.id().into(), amount: BUDGET };
+    assert_eq!(call::<TokenReply>(sys, &token, OWNER, approve), TokenReply::Done);
+    let create = EscrowAction::CreateProject {
+        budget: BUDGET,
+        milestones: vec![MilestoneSpec { weight_bps: 10000, deadline_blocks: 0 }],
+        split: PaymentSplit { upfront_bps: 2000, progress_bps: 4000, final_bps: 4000 },
+        late_penalty_bps: 0,
+        stream_blocks: 0,
+    };
+    let EscrowReply::ProjectCreated { project_id } = call(sys, &escrow, OWNER, create) else {
+        panic!("the project was not created");
+    };
+    let fund = EscrowAction::FundProject { project_id };
+    assert_eq!(call::<EscrowReply>(sys, &escrow, OWNER, fund), EscrowReply::Done);
+    let payees = vec![Payee { account: account(DEV), share_bps: 10000 }];
+    let select = EscrowAction::SelectDeveloper { project_id, payees };
+    assert_eq!(call::<EscrowReply>(sys, &escrow, OWNER, select), EscrowReply::Done);
+
+    (escrow, bridge, project_id)
+}
+
+/// A relayer reports progress on the project's first milestone with passing tests.
+pub fn report(
+    sys: &System,
+    bridge: &Program<'_>,
+    relayer: u64,
+    project_id: ProjectId,
+    percent: u16,
+    nonce: u64,
+) -> BridgeReply {
+    let attestation = BridgeAction::SubmitAttestation {
+        project_id,
+        milestone_idx: 1,
+        percent,
+        tests_passed: true,
+        nonce,
+        expires_at: u32::MAX,
+    };
+    call(sys, bridge, relayer, attestation)
+}
+
+/// The progress the escrow has applied to the project's first milestone.
+pub fn milestone_percent(sys: &System, escrow: &Program<'_>, project_id: ProjectId) -> u16 {
+    match call(sys, escrow, ADMIN, EscrowAction::GetProject { project_id }) {
+        EscrowReply::Project(project) => project.milestones[0].percent,
+        reply => panic!("unexpected reply {reply:?}"),
+    }
+}
+
+pub fn last_percent(sys: &System, bridge: &Program<'_>, project_id: ProjectId) -> u16 {
+    match call(sys, bridge, ADMIN, BridgeAction::GetLastPercent { project_id, milestone_idx: 1 }) {
+        BridgeReply::LastPercent(percent) => percent,
+        reply => panic!("unexpected reply {reply:?}"),
+    }
+}
+
+pub fn tally(sys: &System, bridge: &Program<'_>, project_id: ProjectId) -> Option<Tally> {
+    match call(sys, bridge, ADMIN, BridgeAction::GetTally { project_id, milestone_idx: 1 }) {
+        BridgeReply::Tally(tally) => tally,
+        reply => panic!("unexpected reply {reply:?}"),
+    }
+}
@@ -0,0 +1,35 @@
+//! Median aggregation rounds, run against the bridge's WASM and a live escrow.
+
+mod common;
+
+use common::*;
+use gtest::System;
+use hvs_io::{BridgeAction, BridgeReply, EscrowAction, EscrowReply};
+
+#[test]
+fn new_report_after_a_rejected_round_is_aggregated_in_the_next_one() {
+    let sys = System::new();
+    let (escrow, bridge, project_id) = deploy(&sys);
+    let set_rounds = BridgeAction::SetMedianRounds { window_blocks: 5, max_deviation_bps: 500 };
+    assert_eq!(call::<BridgeReply>(&sys, &bridge, ADMIN, set_rounds), BridgeReply::Done);
+
+    // While a dispute is open the escrow refuses the round's median of 5000; 9000 is an outlier.
+    let raise = EscrowAction::RaiseDispute { project_id };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, OWNER, raise), EscrowReply::Done);
+    assert_eq!(report(&sys, &bridge, RELAYER_1, project_id, 5000, 1), BridgeReply::Done);
+    assert_eq!(report(&sys, &bridge, RELAYER_2, project_id, 5100, 1), BridgeReply::Done);
+    assert_eq!(report(&sys, &bridge, RELAYER_3, project_id, 9000, 1), BridgeReply::Done);
+    run_blocks(&sys, 5);
+    assert_eq!(tally(&sys, &bridge, project_id), None);
+    assert_eq!(milestone_percent(&sys, &escrow, project_id), 0);
+    assert_eq!(last_percent(&sys, &bridge, project_id), 0);
+
+    // Once the dispute is dropped, the next report opens a new round that the escrow accepts.
+    let withdraw = EscrowAction::WithdrawDispute { project_id };
+    assert_eq!(call::<EscrowReply>(&sys, &escrow, OWNER, withdraw), EscrowReply::Done);
+    assert_eq!(report(&sys, &bridge, RELAYER_1, project_id, 6000, 2), BridgeReply::Done);
+    assert_eq!(tally(&sys, &bridge, project_id).map(|round| round.reports.len()), Some(1));
+    run_blocks(&sys, 5);
+    assert_eq!(milestone_percent(&sys, &escrow, project_id), 6000);
+    assert_eq!(last_percent(&sys, &bridge, project_id), 6000);
+}
@@ -0,0 +1,45 @@
+//! k-of-n relayer quorum, run against the bridge's WASM and a live escrow.
+
+mod common;
+
+use common::*;
+use gtest::System;
+use hvs_io::{BridgeAction, BridgeReply, Report};
+
+#[test]
+fn agreeing_reports_forward_the_lowest_once_the_quorum_is_reached() {
+    let sys = System::new();
+    let (escrow, bridge, project_id) = deploy(&sys);
+    let set_quorum = BridgeAction::SetQuorum { threshold: 2, tolerance_bps: 100, timeout_blocks: 10 };
+    assert_eq!(call::<BridgeReply>(&sys, &bridge, ADMIN, set_quorum), BridgeReply::Done);
+
+    // A single report only waits in the tally.
+    assert_eq!(report(&sys, &bridge, RELAYER_1, project_id, 5000, 1), BridgeReply::Done);
+    assert_eq!(tally(&sys, &bridge, project_id).map(|tally| tally.reports.len()), Some(1));
+    assert_eq!(milestone_percent(&sys, &escrow, project_id), 0);
+
+    // A second relayer within the tolerance completes the quorum.
+    assert_eq!(report(&sys, &bridge, RELAYER_2, project_id, 4950, 1), BridgeReply::Done);
+    assert_eq!(tally(&sys, &bridge, project_id), None);
+    assert_eq!(milestone_percent(&sys, &escrow, project_id), 4950);
+    assert_eq!(last_percent(&sys, &bridge, project_id), 4950);
+}
+
+#[test]
+fn tally_expires_before_the_quorum_is_reached() {
+    let sys = System::new();
+    let (escrow, bridge, project_id) = deploy(&sys);
+    let set_quorum = BridgeAction::SetQuorum { threshold: 2, tolerance_bps: 0, timeout_blocks: 10 };
+    assert_eq!(call::<BridgeReply>(&sys, &bridge, ADMIN, set_quorum), BridgeReply::Done);
+
+    assert_eq!(report(&sys, &bridge, RELAYER_1, project_id, 5000, 1), BridgeReply::Done);
+    run_blocks(&sys, 10);
+    assert_eq!(tally(&sys, &bridge, project_id), None);
+
+    // The lapsed report no longer counts, so a matching one starts a new tally instead.
+    assert_eq!(report(&sys, &bridge, RELAYER_2, project_id, 5000, 1), BridgeReply::Done);
+    let reports = tally(&sys, &bridge, project_id).map(|tally| tally.reports);
+    assert_eq!(reports, Some(vec![Report { relayer: account(RELAYER_2), percent: 5000 }]));
+    assert_eq!(milestone_percent(&sys, &escrow, project_id), 0);
+    assert_eq!(last_percent(&sys, &bridge, project_id), 0);
+}
@@ -1,6 +1,17 @@
 #
 ![no_std]
-use gstd::{msg, prelude::*, ActorId};
+use core::ptr::addr_of_mut;
+
+use gstd::{
+    collections::{btree_map::Entry, BTreeMap},
+    exec, msg,
+    prelude::*,
+    ActorId,
+};
+use hvs_io::{
+    decode_action, Attestation, BridgeAction, BridgeEvent, BridgeInit, BridgeReply, Error, EscrowAction, EscrowReply,
+    PolicyReason, ProjectId, Relayer, Report, Tally,
+};
 
 // The state is stored in static mutable variables.
 // This is a common pattern for simple contracts in gstd
@@ -9,21 +20,338 @@ use gstd::{msg, prelude::*, ActorId};
 static mut ESCROW_MANAGER: [u8; 32] = [0; 32];
 static mut ADMIN: [u8; 32] = [0; 32];
 
-static mut RELAYER_1: [u8; 32] = [0; 32];
-static mut RELAYER_2: [u8; 32] = [0; 32];
-static mut RELAYER_3: [u8; 32] = [0; 32];
+// Registered relayers by account.
+static mut RELAYERS: BTreeMap<[u8; 32], Relayer> = BTreeMap::new();
+
+// Highest attestation nonce accepted per relayer; kept when a relayer is removed so its old
+// reports stay spent if it is added again.
+static mut LAST_NONCES: BTreeMap<[u8; 32], u64> = BTreeMap::new();
 
-static mut MILESTONE_1_LAST_PERCENT: u16 = 0;
-static mut MILESTONE_2_LAST_PERCENT: u16 = 0;
-static mut MILESTONE_3_LAST_PERCENT: u16 = 0;
+// Last accepted percentage per (project, milestone); absent entries read as 0.
+static mut LAST_PERCENTS: BTreeMap<(ProjectId, u8), u16> = BTreeMap::new();
 
 static mut POLICY_MIN_STEP_BPS: u16 = 200; // 2% represented as Basis Points (200 / 10000)
 
 static mut POLICY_TEST_REQUIRED: bool = true;
 
+// Distinct relayers that must agree before progress is forwarded; 1 forwards every valid report.
+static mut QUORUM: u8 = 1;
+static mut QUORUM_TOLERANCE_BPS: u16 = 0; // Largest difference between reports counted as agreeing
+static mut TALLY_TIMEOUT_BLOCKS: u32 = 14_400; // About 12 hours of 3-second blocks
+
+// Median mode: reports are collected in rounds of this many blocks (0 = quorum mode).
+static mut MEDIAN_WINDOW_BLOCKS: u32 = 0;
+static mut MEDIAN_MAX_DEVIATION_BPS: u16 = 0; // Reports further than this from the median are outliers
+
+// Reports awaiting a quorum per (project, milestone).
+static mut PENDING_TALLIES: BTreeMap<(ProjectId, u8), Tally> = BTreeMap::new();
+
 // A constant to represent an empty/unassigned ActorId.
 const ZERO_ACTOR: [u8; 32] = [0; 32];
 
+const MAX_LABEL_LEN: usize = 64;
+
+/// The maps are reached through raw pointers so no reference to a `static mut` is taken directly.
+unsafe fn relayers() -> &'static mut BTreeMap<[u8; 32], Relayer> {
+    &mut *addr_of_mut!(RELAYERS)
+}
+
+unsafe fn last_nonces() -> &'static mut BTreeMap<[u8; 32], u64> {
+    &mut *addr_of_mut!(LAST_NONCES)
+}
+
+unsafe fn last_percents() -> &'static mut BTreeMap<(ProjectId, u8), u16> {
+    &mut *addr_of_mut!(LAST_PERCENTS)
+}
+
+unsafe fn pending_tallies() -> &'static mut BTreeMap<(ProjectId, u8), Tally> {
+    &mut *addr_of_mut!(PENDING_TALLIES)
+}
+
+/// Emits an event by sending it to the zero address, following Gear's event convention.
+fn emit_event(event: BridgeEvent) {
+    msg::send(ActorId::zero(), event, 0).expect("Failed to emit event");
+}
+
+/// Returns the last accepted percentage of a project's milestone, or 0 if none was accepted yet.
+///
+/// The number of milestones is defined per project by the escrow, which rejects unknown indices.
+unsafe fn last_percent(project_id: ProjectId, milestone_idx: u8) -> u16 {
+    last_percents().get(&(project_id, milestone_idx)).copied().unwrap_or(0)
+}
+
+/// Whether `account` is a registered, enabled relayer.
+unsafe fn is_active_relayer(account: [u8; 32]) -> bool {
+    relayers().get(&account).is_some_and(|relayer| relayer.enabled)
+}
+
+/// Rejects an expired attestation or one whose nonce was already used, then spends the nonce.
+///
+/// Runs before any await so the same report cannot be applied twice.
+unsafe fn consume_nonce(relayer: [u8; 32], nonce: u64, expires_at: u32) -> Result<(), Error> {
+    if exec::block_height() > expires_at {
+        return Err(Error::AttestationExpired);
+    }
+    if last_nonces().get(&relayer).is_some_and(|&last| nonce <= last) {
+        return Err(Error::NonceReused);
+    }
+
+    last_nonces().insert(relayer, nonce);
+    Ok(())
+}
+
+/// Drops a relayer's reports from every pending tally so they no longer count towards a result.
+unsafe fn drop_reports_of(relayer: [u8; 32]) {
+    for tally in pending_tallies().values_mut() {
+        tally.reports.retain(|report| report.relayer != relayer);
+    }
+}
+
+/// Adds a relayer's report to the milestone's pending tally and returns the agreed percentage once
+/// `QUORUM` distinct relayers have reported within `QUORUM_TOLERANCE_BPS` of it.
+///
+/// The agreed percentage is the lowest of the agreeing reports, so no relayer vouches for more
+/// than it observed. The tally is cleared when it reaches the quorum or expires.
+unsafe fn tally_report(
+    project_id: ProjectId,
+    milestone_idx: u8,
+    relayer: [u8; 32],
+    percent: u16,
+) -> Result<Option<u16>, Error> {
+    let key = (project_id, milestone_idx);
+    let now = exec::block_height();
+
+    // The expiry wake-up may not have arrived yet.
+    if pending_tallies().get(&key).is_some_and(|tally| now >= tally.expires_at) {
+        pending_tallies().remove(&key);
+        emit_event(BridgeEvent::TallyExpired { project_id, milestone_idx });
+    }
+
+    let tally = match pending_tallies().entry(key) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            // A wake-up for an earlier tally of the same milestone is ignored as not yet due.
+            let wake_up = BridgeAction::ExpireTally { project_id, milestone_idx };
+            msg::send_delayed(exec::program_id(), wake_up, 0, TALLY_TIMEOUT_BLOCKS)
+                .map_err(|_| Error::ExternalCallFailed)?;
+            entry.insert(Tally { expires_at: now.saturating_add(TALLY_TIMEOUT_BLOCKS), reports: Vec::new() })
+        }
+    };
+
+    // A relayer's latest report replaces its earlier one.
+    tally.reports.retain(|report| report.relayer != relayer);
+    tally.reports.push(Report { relayer, percent });
+
+    let agreeing = tally
+        .reports
+        .iter()
+        .map(|report| report.percent)
+        .filter(|other| other.abs_diff(percent) <= QUORUM_TOLERANCE_BPS);
+    let votes = agreeing.clone().count();
+    if votes < QUORUM as usize {
+        emit_event(BridgeEvent::AttestationPending {
+            relayer,
+            project_id,
+            milestone_idx,
+            percent,
+            votes: votes as u32,
+        });
+        return Ok(None);
+    }
+
+    let agreed = agreeing.min().unwrap_or(percent);
+    pending_tallies().remove(&key);
+    Ok(Some(agreed))
+}
+
+/// Adds a relayer's report to the milestone's round, opening one if none is running.
+///
+/// The round is aggregated by a delayed self-message once its window ends, or by the next report
+/// if that message never arrives.
+unsafe fn add_round_report(
+    project_id: ProjectId,
+    milestone_idx: u8,
+    relayer: [u8; 32],
+    percent: u16,
+) -> Result<(), Error> {
+    let now = exec::block_height();
+
+    let round = match pending_tallies().entry((project_id, milestone_idx)) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            let wake_up = BridgeAction::CloseRound { project_id, milestone_idx };
+            msg::send_delayed(exec::program_id(), wake_up, 0, MEDIAN_WINDOW_BLOCKS)
+                .map_err(|_| Error::ExternalCallFailed)?;
+            entry.insert(Tally { expires_at: now.saturating_add(MEDIAN_WINDOW_BLOCKS), reports: Vec::new() })
+        }
+    };
+
+    // A relayer's latest report replaces its earlier one.
+    round.reports.retain(|report| report.relayer != relayer);
+    round.reports.push(Report { relayer, percent });
+
+    emit_event(BridgeEvent::AttestationPending {
+        relayer,
+        project_id,
+        milestone_idx,
+        percent,
+        votes: round.reports.len() as u32,
+    });
+    Ok(())
+}
+
+/// Returns the lower middle value of sorted percentages.
+fn lower_median(sorted: &[u16]) -> u16 {
+    sorted.get(sorted.len().saturating_sub(1) / 2).copied().unwrap_or(0)
+}
+
+/// Aggregates a round: discards reports further than `MEDIAN_MAX_DEVIATION_BPS` from the median of
+/// all of them and returns the median of the rest with how many were kept.
+///
+/// Even counts take the lower middle value, so the result never exceeds what half the relayers saw.
+unsafe fn round_median(reports: &[Report]) -> (u16, usize) {
+    let mut percents: Vec<u16> = reports.iter().map(|report| report.percent).collect();
+    percents.sort_unstable();
+
+    let median = lower_median(&percents);
+    percents.retain(|percent| percent.abs_diff(median) <= MEDIAN_MAX_DEVIATION_BPS);
+    (lower_median(&percents), percents.len())
+}
+
+/// Closes the milestone's round once its window has ended and forwards the median of its reports
+/// to the escrow. A round that is still open, or none at all, is left alone.
+async unsafe fn close_round(project_id: ProjectId, milestone_idx: u8) -> Result<(), Error> {
+    let key = (project_id, milestone_idx);
+    let due = pending_tallies().get(&key).is_some_and(|round| exec::block_height() >= round.expires_at);
+    if !due {
+        return Ok(());
+    }
+    let round = pending_tallies().remove(&key).ok_or(Error::NotFound)?;
+
+    // Too few reports survived the outlier cut: the round lapses like an expired tally.
+    let (percent, counted) = round_median(&round.reports);
+    if counted < QUORUM as usize {
+        emit_event(BridgeEvent::TallyExpired { project_id, milestone_idx });
+        return Ok(());
+    }
+
+    if let Err(error) = apply_progress_on_escrow(project_id, milestone_idx, percent).await {
+        emit_event(BridgeEvent::RoundRejected { project_id, milestone_idx, percent });
+        return Err(error);
+    }
+
+    // Another attestation may have been accepted while awaiting, so never move backwards.
+    let last_percent_ref = last_percents().entry(key).or_insert(0);
+    *last_percent_ref = (*last_percent_ref).max(percent);
+
+    emit_event(BridgeEvent::RoundAccepted {
+        project_id,
+        milestone_idx,
+        percent,
+        counted: counted as u32,
+        discarded: (round.reports.len() - counted) as u32,
+    });
+    Ok(())
+}
+
+/// Forwards accepted progress to the escrow manager as `APPLY_PROGRESS` and waits for its verdict.
+async fn apply_progress_on_escrow(project_id: ProjectId, milestone_idx: u8, percent: u16) -> Result<(), Error> {
+    let escrow = unsafe { ActorId::from(ESCROW_MANAGER) };
+    let action = EscrowAction::ApplyProgress { project_id, milestone_idx, percent };
+    let reply = msg::send_for_reply_as::<_, EscrowReply>(escrow, action, 0, 0)
+        .map_err(|_| Error::ExternalCallFailed)?
+        .await
+        .map_err(|_| Error::ExternalCallFailed)?;
+
+    match reply {
+        EscrowReply::Done => Ok(()),
+        EscrowReply::Failed(error) => Err(error),
+        _ => Err(Error::ExternalCallFailed),
+    }
+}
+
+/// Applies the bridge's policies to a relayer's report and forwards the resulting progress to the
+/// escrow, directly or once the quorum or median round allows.
+async unsafe fn attest(
+    relayer: [u8; 32],
+    project_id: ProjectId,
+    milestone_idx: u8,
+    new_percent: u16,
+    tests_passed: bool,
+) -> Result<(), Error> {
+    // A round whose wake-up never arrived is closed by the next report, which then opens a new one.
+    // The escrow rejecting the old round was already reported as `RoundRejected` and must not drop
+    // this report, whose nonce is spent.
+    if MEDIAN_WINDOW_BLOCKS > 0 {
+        let _ = close_round(project_id, milestone_idx).await;
+    }
+
+    let last = last_percent(project_id, milestone_idx);
+
+    // Perform validation checks, reporting the first policy that fails.
+    let violation = if new_percent <= last {
+        Some(PolicyReason::NotMonotonic)
+    } else if new_percent - last < POLICY_MIN_STEP_BPS {
+        Some(PolicyReason::BelowMinStep)
+    } else if POLICY_TEST_REQUIRED && !tests_passed {
+        Some(PolicyReason::TestsNotPassed)
+    } else {
+        None
+    };
+
+    if let Some(reason) = violation {
+        // Attestation failed validation, reply with the violated policy.
+        emit_event(BridgeEvent::AttestationRejected {
+            relayer,
+            project_id,
+            milestone_idx,
+            percent: new_percent,
+        });
+        return Err(Error::PolicyViolation { reason });
+    }
+
+    // In median mode the report joins the milestone's round and is forwarded when it closes.
+    if MEDIAN_WINDOW_BLOCKS > 0 {
+        add_round_report(project_id, milestone_idx, relayer, new_percent)?;
+        return Ok(());
+    }
+
+    // Below the quorum the report waits in the milestone's tally for other relayers to agree.
+    let percent = if QUORUM > 1 {
+        match tally_report(project_id, milestone_idx, relayer, new_percent)? {
+            Some(agreed) => agreed,
+            None => return Ok(()),
+        }
+    } else {
+        new_percent
+    };
+
+    // The escrow is the source of truth: only record the percentage once it has applied it.
+    // A rejected forward also drops the tally; relayers report again once the escrow accepts.
+    if let Err(error) = apply_progress_on_escrow(project_id, milestone_idx, percent).await {
+        emit_event(BridgeEvent::AttestationRejected {
+            relayer,
+            project_id,
+            milestone_idx,
+            percent,
+        });
+        return Err(error);
+    }
+
+    // Another attestation may have been accepted while awaiting, so never move backwards.
+    let last_percent_ref = last_percents().entry((project_id, milestone_idx)).or_insert(0);
+    *last_percent_ref = (*last_percent_ref).max(percent);
+
+    emit_event(BridgeEvent::AttestationAccepted {
+        relayer,
+        project_id,
+        milestone_idx,
+        percent,
+    });
+
+    Ok(())
+}
+
 /// Initializes the verification bridge contract.
 ///
 /// This function is called once upon contract creation.
@@ -31,138 +359,232 @@ const ZERO_ACTOR: [u8; 32] = [0; 32];
 /// the escrow manager from the provided payload.
 ///
 /// # Payload
-/// `[0 (action byte), escrow_manager (32 bytes)]`
+/// `BridgeInit`: `[escrow_manager (32 bytes)]`
 ///
 /// # Reply
-/// `[1]` on success.
+/// `BridgeReply::Done` on success.
 #[no_mangle]
 extern "C" fn init() {
-    let payload = msg::load_bytes().expect("Failed to load init payload");
-    gstd::assert!(payload.len() == 33, "Invalid init payload length");
+    let init: BridgeInit = msg::load().expect("Invalid init payload");
 
     unsafe {
         ADMIN = msg::source().into();
-        ESCROW_MANAGER = payload[1..33]
-            .try_into()
-            .expect("Invalid escrow_manager bytes");
+        ESCROW_MANAGER = init.escrow_manager;
     }
 
     // Reply with a success code.
-    msg::reply_bytes(&[1], 0).expect("Failed to reply in init");
+    msg::reply(BridgeReply::Done, 0).expect("Failed to reply in init");
 }
 
 /// Handles incoming messages to the contract.
 ///
 /// This function is the main entry point for all subsequent interactions.
-/// It uses the first byte of the payload to dispatch to the correct action handler.
-#[no_mangle]
-extern "C" fn handle() {
-    let cmd = msg::load_bytes().expect("Failed to load handle payload");
-    gstd::assert!(!cmd.is_empty(), "Handle payload cannot be empty");
+/// The payload is decoded as a `BridgeAction`; failures are replied as `BridgeReply::Failed`.
+#[gstd::async_main]
+async fn main() {
+    let payload = msg::load_bytes().expect("Failed to load handle payload");
+    let reply = match decode_action::<BridgeAction>(&payload) {
+        Ok(action) => unsafe { process(action).await },
+        Err(error) => Err(error),
+    };
 
-    unsafe {
-        match cmd[0] {
-            // Action 1: Set a relayer in a specific slot.
-            // Payload: [1, relayer_actor_id (32 bytes), slot (1 byte: 1, 2, or 3)]
-            1 => {
-                gstd::assert_eq!(<[u8; 32]>::from(msg::source()), ADMIN, "Only admin can set relayers");
-                gstd::assert!(cmd.len() == 34, "Invalid SET_RELAYER payload length");
-
-                let relayer: [u8; 32] = cmd[1..33].try_into().expect("Invalid relayer bytes");
-                let slot = cmd[33];
-
-                match slot {
-                    1 => RELAYER_1 = relayer,
-                    2 => RELAYER_2 = relayer,
-                    3 => RELAYER_3 = relayer,
-                    _ => panic!("Invalid relayer slot. Must be 1, 2, or 3."),
-                }
-
-                msg::reply_bytes(&[1], 0).expect("Failed to reply to SET_RELAYER");
-            }
-
-            // Action 2: A relayer submits an attestation for a milestone.
-            // Payload: [2, milestone_idx (1 byte), new_percent (2 bytes LE), tests_passed (1 byte: 0 or 1)]
-            2 => {
-                let source_actor: [u8; 32] = msg::source().into();
-                gstd::assert!(
-                    source_actor == RELAYER_1
-                        || source_actor == RELAYER_2
-                        || source_actor == RELAYER_3,
-                    "Only whitelisted relayers can submit attestations"
-                );
-                 gstd::assert!(cmd.len() == 5, "Invalid SUBMIT_ATTESTATION payload length");
-
-                let milestone_idx = cmd[1];
-                let new_percent = u16::from_le_bytes(cmd[2..4].try_into().expect("Invalid new_percent bytes"));
-                let tests_passed = cmd[4];
-
-                let last_percent_ref = match milestone_idx {
-                    1 => &mut MILESTONE_1_LAST_PERCENT,
-                    2 => &mut MILESTONE_2_LAST_PERCENT,
-                    3 => &mut MILESTONE_3_LAST_PERCENT,
-                    _ => panic!("Invalid milestone index. Must be 1, 2, or 3."),
-                };
-                
-                // Perform validation checks.
-                let is_monotonic = new_percent > *last_percent_ref;
-                let meets_min_step = (new_percent.saturating_sub(*last_percent_ref)) >= POLICY_MIN_STEP_BPS;
-                let tests_ok = !POLICY_TEST_REQUIRED || tests_passed == 1;
-
-                if is_monotonic && meets_min_step && tests_ok {
-                    *last_percent_ref = new_percent;
-
-                    // Construct and send the message to the escrow contract.
-                    // The escrow contract is expected to handle a payload of:
-                    // [milestone_idx (1 byte), new_percent (2 bytes LE)]
-                    let mut escrow_payload = Vec::with_capacity(3);
-                    escrow_payload.push(milestone_idx);
-                    escrow_payload.extend_from_slice(&new_percent.to_le_bytes());
-                    
-                    msg::send_bytes(ActorId::from(ESCROW_MANAGER), escrow_payload, 0)
-                        .expect("Failed to send message to escrow contract");
-                    
-                    msg::reply_bytes(&[1], 0).expect("Failed to reply to SUBMIT_ATTESTATION");
-                } else {
-                    // Attestation failed validation, reply with failure code.
-                    msg::reply_bytes(&[0], 0).expect("Failed to reply to SUBMIT_ATTESTATION");
-                }
-            }
-
-            // Action 3: Get the last reported percentage for a milestone.
-            // Payload: [3, milestone_idx (1 byte)]
-            3 => {
-                gstd::assert!(cmd.len() == 2, "Invalid GET_LAST_PERCENT payload length");
-                let milestone_idx = cmd[1];
-
-                let last_percent = match milestone_idx {
-                    1 => MILESTONE_1_LAST_PERCENT,
-                    2 => MILESTONE_2_LAST_PERCENT,
-                    3 => MILESTONE_3_LAST_PERCENT,
-                    _ => panic!("Invalid milestone index. Must be 1, 2, or 3."),
-                };
-
-                msg::reply_bytes(&last_percent.to_le_bytes(), 0)
-                    .expect("Failed to reply to GET_LAST_PERCENT");
-            }
-            
-            // Action 4: Update the escrow manager contract address.
-            // Payload: [4, new_escrow_manager (32 bytes)]
-            4 => {
-                gstd::assert_eq!(<[u8; 32]>::from(msg::source()), ADMIN, "Only admin can set escrow manager");
-                gstd::assert!(cmd.len() == 33, "Invalid SET_ESCROW payload length");
-
-                let new_escrow: [u8; 32] = cmd[1..33].try_into().expect("Invalid escrow bytes");
-                gstd::assert!(new_escrow != ZERO_ACTOR, "Escrow manager cannot be the zero address");
-                ESCROW_MANAGER = new_escrow;
-                
-                msg::reply_bytes(&[1], 0).expect("Failed to reply to SET_ESCROW");
-            }
-
-            _ => {
-                // Unknown action, do nothing or panic.
-                panic!("Unknown action");
+    msg::reply(reply.unwrap_or_else(BridgeReply::Failed), 0).expect("Failed to reply");
+}
+
+/// Executes a single action and returns the reply to send.
+async unsafe fn process(action: BridgeAction) -> Result<BridgeReply, Error> {
+    match action {
+        // A relayer submits an attestation for a milestone.
+        BridgeAction::SubmitAttestation { project_id, milestone_idx, percent, tests_passed, nonce, expires_at } => {
+            let source_actor: [u8; 32] = msg::source().into();
+            if !is_active_relayer(source_actor) {
+                return Err(Error::Unauthorized);
+            }
+            consume_nonce(source_actor, nonce, expires_at)?;
+
+            attest(source_actor, project_id, milestone_idx, percent, tests_passed).await?;
+        }
+
+        // Anyone relays an attestation signed off-chain with a relayer's key.
+        BridgeAction::SubmitSignedAttestation { relayer, attestation, signature } => {
+            let key = match relayers().get(&relayer) {
+                Some(Relayer { enabled: true, key: Some(key), .. }) => *key,
+                _ => return Err(Error::Unauthorized),
+            };
+            let bridge: [u8; 32] = exec::program_id().into();
+            if !key.verify(&attestation.signing_message(bridge), &signature) {
+                return Err(Error::Unauthorized);
+            }
+            consume_nonce(relayer, attestation.nonce, attestation.expires_at)?;
+
+            let Attestation { project_id, milestone_idx, percent, tests_passed, .. } = attestation;
+            attest(relayer, project_id, milestone_idx, percent, tests_passed).await?;
+        }
+
+        // Get the last reported percentage for a project's milestone.
+        BridgeAction::GetLastPercent { project_id, milestone_idx } => {
+            return Ok(BridgeReply::LastPercent(last_percent(project_id, milestone_idx)));
+        }
+
+        // Update the escrow manager contract address.
+        BridgeAction::SetEscrow { escrow_manager } => {
+            if <[u8; 32]>::from(msg::source()) != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            if escrow_manager == ZERO_ACTOR {
+                return Err(Error::InvalidPayload);
+            }
+
+            ESCROW_MANAGER = escrow_manager;
+        }
+
+        // Configure how many relayers must agree before progress is forwarded.
+        BridgeAction::SetQuorum { threshold, tolerance_bps, timeout_blocks } => {
+            if <[u8; 32]>::from(msg::source()) != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            if threshold == 0 || tolerance_bps > 10000 || timeout_blocks == 0 {
+                return Err(Error::InvalidPayload);
+            }
+
+            // Tallies already collected are judged against the new settings on their next report.
+            QUORUM = threshold;
+            QUORUM_TOLERANCE_BPS = tolerance_bps;
+            TALLY_TIMEOUT_BLOCKS = timeout_blocks;
+        }
+
+        // Get the reports still waiting for a quorum on a project's milestone.
+        BridgeAction::GetTally { project_id, milestone_idx } => {
+            let now = exec::block_height();
+            let tally = pending_tallies()
+                .get(&(project_id, milestone_idx))
+                .filter(|tally| now < tally.expires_at)
+                .cloned();
+            return Ok(BridgeReply::Tally(tally));
+        }
+
+        // Drop a tally that did not reach the quorum in time.
+        BridgeAction::ExpireTally { project_id, milestone_idx } => {
+            if msg::source() != exec::program_id() {
+                return Err(Error::Unauthorized);
+            }
+
+            // Wake-ups scheduled for an earlier tally of the milestone, or in another mode, are ignored.
+            let key = (project_id, milestone_idx);
+            let due = pending_tallies().get(&key).is_some_and(|tally| exec::block_height() >= tally.expires_at);
+            if due && MEDIAN_WINDOW_BLOCKS == 0 {
+                pending_tallies().remove(&key);
+                emit_event(BridgeEvent::TallyExpired { project_id, milestone_idx });
+            }
+        }
+
+        // Register a new relayer, enabled from the start.
+        BridgeAction::AddRelayer { relayer, label } => {
+            if <[u8; 32]>::from(msg::source()) != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            if relayer == ZERO_ACTOR {
+                return Err(Error::InvalidPayload);
+            }
+            if label.len() > MAX_LABEL_LEN {
+                return Err(Error::CapacityExceeded);
+            }
+            if relayers().contains_key(&relayer) {
+                return Err(Error::InvalidState);
+            }
+
+            relayers().insert(
+                relayer,
+                Relayer {
+                    account: relayer,
+                    label: label.clone(),
+                    added_at: exec::block_height(),
+                    enabled: true,
+                    key: None,
+                },
+            );
+
+            emit_event(BridgeEvent::RelayerAdded { relayer, label });
+        }
+
+        // Unregister a relayer.
+        BridgeAction::RemoveRelayer { relayer } => {
+            if <[u8; 32]>::from(msg::source()) != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            if relayers().remove(&relayer).is_none() {
+                return Err(Error::NotFound);
+            }
+
+            drop_reports_of(relayer);
+
+            emit_event(BridgeEvent::RelayerRemoved { relayer });
+        }
+
+        // Pause or resume a relayer without losing its registration.
+        BridgeAction::SetRelayerEnabled { relayer, enabled } => {
+            if <[u8; 32]>::from(msg::source()) != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            let entry = relayers().get_mut(&relayer).ok_or(Error::NotFound)?;
+
+            entry.enabled = enabled;
+            if !enabled {
+                drop_reports_of(relayer);
+            }
+
+            emit_event(BridgeEvent::RelayerEnabled { relayer, enabled });
+        }
+
+        // Register or clear the key a relayer signs attestations with.
+        BridgeAction::SetRelayerKey { relayer, key } => {
+            if <[u8; 32]>::from(msg::source()) != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            relayers().get_mut(&relayer).ok_or(Error::NotFound)?.key = key;
+
+            emit_event(BridgeEvent::RelayerKeySet { relayer, key });
+        }
+
+        // List the registered relayers.
+        BridgeAction::GetRelayers { active_only } => {
+            let relayers = relayers()
+                .values()
+                .filter(|relayer| relayer.enabled || !active_only)
+                .cloned()
+                .collect();
+            return Ok(BridgeReply::Relayers(relayers));
+        }
+
+        // Switch between quorum mode and median rounds.
+        BridgeAction::SetMedianRounds { window_blocks, max_deviation_bps } => {
+            if <[u8; 32]>::from(msg::source()) != ADMIN {
+                return Err(Error::Unauthorized);
+            }
+            if max_deviation_bps > 10000 {
+                return Err(Error::InvalidPayload);
+            }
+
+            // Tallies collected under one mode cannot be completed under the other.
+            if (window_blocks > 0) != (MEDIAN_WINDOW_BLOCKS > 0) {
+                pending_tallies().clear();
+            }
+            MEDIAN_WINDOW_BLOCKS = window_blocks;
+            MEDIAN_MAX_DEVIATION_BPS = max_deviation_bps;
+        }
+
+        // Aggregate a round whose window has ended and forward its median.
+        BridgeAction::CloseRound { project_id, milestone_idx } => {
+            if msg::source() != exec::program_id() {
+                return Err(Error::Unauthorized);
+            }
+
+            // Wake-ups scheduled for an earlier round of the milestone, or in another mode, are ignored.
+            if MEDIAN_WINDOW_BLOCKS > 0 {
+                close_round(project_id, milestone_idx).await?;
             }
         }
     }
-}
\ No newline at end of file
+
+    Ok(BridgeReply::Done)
+}